@@ -0,0 +1,63 @@
+use crate::{Endidness, Error, Result};
+use binreader_macros::make_number_methods;
+
+/// An asynchronous counterpart to [`BinReader`](crate::BinReader), for data that arrives over
+/// time (such as from a socket). Since the data isn't all available up front, only sequential
+/// reads are supported, but offsets and endidness work the same way as they do for a
+/// [`BinReader`](crate::BinReader).
+///
+/// Note that the methods are declared as `async fn`s, so the futures they return don't have a
+/// `Send` bound. Code that's generic over an [`AsyncBinReader`] therefore can't spawn those
+/// futures onto a multi-threaded runtime, although code that uses a concrete reader (such as
+/// [`StreamBinReader`](crate::StreamBinReader)) can, as long as that reader is `Send`.
+#[allow(async_fn_in_trait)]
+pub trait AsyncBinReader {
+    /// The offset of the first byte read from the underlying source.
+    fn initial_offset(&self) -> usize;
+
+    /// The offset of the next byte to be read.
+    fn current_offset(&self) -> usize;
+
+    /// The default endidness used when reading numbers.
+    fn endidness(&self) -> Endidness;
+
+    /// Changes the default endidness.
+    fn change_endidness(&mut self, endidness: Endidness);
+
+    /// Gets the next `num_bytes` bytes, waiting for them to arrive if needed, and then advances
+    /// the [`AsyncBinReader::current_offset`] past them. If the underlying source ends before
+    /// enough bytes arrive, then an error is returned and nothing is consumed.
+    async fn next_n_bytes(&mut self, num_bytes: usize) -> Result<&[u8]>;
+
+    /// Gets the next `u8` and then advances the [`AsyncBinReader::current_offset`] by `1`.
+    async fn next_u8(&mut self) -> Result<u8> {
+        Ok(self.next_n_bytes(1).await?[0])
+    }
+
+    make_number_methods! {
+        /// Gets the next numendlong endian `numname` and then advances the
+        /// [`AsyncBinReader::current_offset`] past it.
+        async fn next_numname_numend(&mut self) -> Result<_numname_> {
+            let mut buf = [0; _numwidth_];
+            buf.copy_from_slice(self.next_n_bytes(_numwidth_).await?);
+            Ok(_numname_::from_numend_bytes(buf))
+        }
+    }
+
+    /// Gets the next `i8` and then advances the [`AsyncBinReader::current_offset`] by `1`.
+    async fn next_i8(&mut self) -> Result<i8> {
+        Ok(self.next_u8().await? as i8)
+    }
+
+    default_endidness_methods! {
+        async AsyncBinReader;
+        u16: next_u16, next_u16_be, next_u16_le;
+        u32: next_u32, next_u32_be, next_u32_le;
+        u64: next_u64, next_u64_be, next_u64_le;
+        u128: next_u128, next_u128_be, next_u128_le;
+        i16: next_i16, next_i16_be, next_i16_le;
+        i32: next_i32, next_i32_be, next_i32_le;
+        i64: next_i64, next_i64_be, next_i64_le;
+        i128: next_i128, next_i128_be, next_i128_le;
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{BinReader, Error, Result};
+
+/// The order in which a [`BitReader`] reads the bits of each byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    /// The most significant bit of each byte is read first.
+    MsbFirst,
+    /// The least significant bit of each byte is read first.
+    LsbFirst,
+}
+
+/// Reads individual bits from a borrowed [`BinReader`]. The underlying reader's
+/// [`BinReader::current_offset`] is advanced as each byte is fully consumed, so a partially read
+/// byte is still the underlying reader's current byte.
+pub struct BitReader<'a, B> {
+    reader: &'a B,
+    order: BitOrder,
+    bit_pos: u8,
+}
+
+impl<'a, 'r, B: BinReader<'r>> BitReader<'a, B> {
+    /// Creates a new [`BitReader`] that starts at the beginning of the reader's current byte.
+    pub fn new(reader: &'a B, order: BitOrder) -> Self {
+        Self {
+            reader,
+            order,
+            bit_pos: 0,
+        }
+    }
+
+    /// How many bits of the underlying reader's current byte have already been read.
+    #[inline]
+    pub fn bit_position(&self) -> u8 {
+        self.bit_pos
+    }
+
+    /// Reads a single bit.
+    pub fn read_bit(&mut self) -> Result<bool> {
+        let byte = self.reader.u8_at(self.reader.current_offset())?;
+        let bit = match self.order {
+            BitOrder::MsbFirst => (byte >> (7 - self.bit_pos)) & 1,
+            BitOrder::LsbFirst => (byte >> self.bit_pos) & 1,
+        };
+        if self.bit_pos == 7 {
+            self.reader.advance_by(1)?;
+            self.bit_pos = 0;
+        } else {
+            self.bit_pos += 1;
+        }
+        Ok(bit == 1)
+    }
+
+    /// Reads `num_bits` bits (at most `64`) as an unsigned integer. When reading most
+    /// significant bit first, the first bit read is the value's most significant bit; otherwise,
+    /// it's the value's least significant bit. If there aren't enough bits left, then nothing is
+    /// consumed.
+    pub fn read_bits(&mut self, num_bits: u8) -> Result<u64> {
+        if num_bits > 64 {
+            return Err(Error::Other(format!(
+                "Cannot read {} bits at once; the maximum is 64.",
+                num_bits
+            )));
+        }
+        let start = (self.reader.current_offset(), self.bit_pos);
+        let mut value = 0;
+        for i in 0..num_bits {
+            let bit = match self.read_bit() {
+                Ok(bit) => bit as u64,
+                Err(e) => {
+                    self.reader.advance_to(start.0)?;
+                    self.bit_pos = start.1;
+                    return Err(e);
+                }
+            };
+            value = match self.order {
+                BitOrder::MsbFirst => (value << 1) | bit,
+                BitOrder::LsbFirst => value | (bit << i),
+            };
+        }
+        Ok(value)
+    }
+
+    /// Skips the rest of a partially read byte so that the next read starts on a byte boundary.
+    /// This does nothing if already on a byte boundary.
+    pub fn align(&mut self) -> Result<()> {
+        if self.bit_pos != 0 {
+            self.reader.advance_by(1)?;
+            self.bit_pos = 0;
+        }
+        Ok(())
+    }
+}
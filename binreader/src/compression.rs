@@ -0,0 +1,62 @@
+use crate::{Error, Result};
+use bytes::Bytes;
+use std::io::Read as _;
+
+#[cfg(feature = "snappy")]
+/// Which of Snappy's two formats a block of compressed data is stored in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnappyFormat {
+    /// The raw block format, with no framing or checksums.
+    Raw,
+    /// The framing format, which breaks the data up into checksummed chunks.
+    Framed,
+}
+
+#[cfg(feature = "snappy")]
+pub(crate) fn snappy_decompress(data: &[u8], format: SnappyFormat) -> Result<Bytes> {
+    let decompressed = match format {
+        SnappyFormat::Raw => snap::raw::Decoder::new()
+            .decompress_vec(data)
+            .map_err(|e| Error::Decompression(e.to_string()))?,
+        SnappyFormat::Framed => {
+            let mut buf = Vec::new();
+            snap::read::FrameDecoder::new(data)
+                .read_to_end(&mut buf)
+                .map_err(|e| Error::Decompression(e.to_string()))?;
+            buf
+        }
+    };
+    Ok(Bytes::from(decompressed))
+}
+
+#[cfg(feature = "lz4")]
+pub(crate) fn lz4_block_decompress(data: &[u8], decompressed_len: usize) -> Result<Bytes> {
+    let decompressed = lz4_flex::block::decompress(data, decompressed_len)
+        .map_err(|e| Error::Decompression(e.to_string()))?;
+    if decompressed.len() != decompressed_len {
+        return Err(Error::Decompression(format!(
+            "Expected {} decompressed bytes, but got {}.",
+            decompressed_len,
+            decompressed.len()
+        )));
+    }
+    Ok(Bytes::from(decompressed))
+}
+
+#[cfg(feature = "lz4")]
+pub(crate) fn lz4_frame_decompress(data: &[u8]) -> Result<Bytes> {
+    let mut buf = Vec::new();
+    lz4_flex::frame::FrameDecoder::new(data)
+        .read_to_end(&mut buf)
+        .map_err(|e| Error::Decompression(e.to_string()))?;
+    Ok(Bytes::from(buf))
+}
+
+#[cfg(feature = "zlib")]
+pub(crate) fn zlib_decompress(data: &[u8]) -> Result<Bytes> {
+    let mut buf = Vec::new();
+    flate2::read::ZlibDecoder::new(data)
+        .read_to_end(&mut buf)
+        .map_err(|e| Error::Decompression(e.to_string()))?;
+    Ok(Bytes::from(buf))
+}
@@ -0,0 +1,178 @@
+use crate::{BinReader, Endidness, Error, Result};
+use std::fmt;
+
+/// A single condition that a reader's data has to meet, to be checked via [`BinReader::check`].
+///
+/// All of the numeric constraints read unsigned integers that are `width` bytes wide (between
+/// `1` and `8`) at an absolute offset, using an explicit endidness instead of the reader's. Any
+/// other width results in an [`Error::Other`] when the constraint is checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Constraint {
+    /// The bytes at `offset` must be exactly `magic`.
+    Magic { offset: usize, magic: Vec<u8> },
+    /// The integer at `offset` must be equal to `value`.
+    Equals {
+        offset: usize,
+        width: usize,
+        endidness: Endidness,
+        value: u64,
+    },
+    /// The integer at `offset` must be between `min` and `max` (inclusive).
+    InRange {
+        offset: usize,
+        width: usize,
+        endidness: Endidness,
+        min: u64,
+        max: u64,
+    },
+    /// The integer at `offset` must be a multiple of `alignment`.
+    Aligned {
+        offset: usize,
+        width: usize,
+        endidness: Endidness,
+        alignment: u64,
+    },
+}
+
+macro_rules! numeric_constructors {
+    ($($type:ident, $width:literal: $eq:ident, $in_range:ident, $aligned:ident;)+) => {
+        $(
+            #[doc = concat!("Requires the `", stringify!($type), "` at `offset` to equal `value`.")]
+            pub fn $eq(offset: usize, value: $type, endidness: Endidness) -> Self {
+                Self::Equals { offset, width: $width, endidness, value: value as u64 }
+            }
+
+            #[doc = concat!("Requires the `", stringify!($type), "` at `offset` to be between ")]
+            #[doc = "`min` and `max` (inclusive)."]
+            pub fn $in_range(offset: usize, min: $type, max: $type, endidness: Endidness) -> Self {
+                Self::InRange {
+                    offset,
+                    width: $width,
+                    endidness,
+                    min: min as u64,
+                    max: max as u64,
+                }
+            }
+
+            #[doc = concat!("Requires the `", stringify!($type), "` at `offset` to be a multiple ")]
+            #[doc = "of `alignment`."]
+            pub fn $aligned(offset: usize, alignment: $type, endidness: Endidness) -> Self {
+                Self::Aligned { offset, width: $width, endidness, alignment: alignment as u64 }
+            }
+        )+
+    };
+}
+
+impl Constraint {
+    /// Requires the bytes at `offset` to be exactly `magic`.
+    pub fn magic(offset: usize, magic: &[u8]) -> Self {
+        Self::Magic {
+            offset,
+            magic: magic.to_vec(),
+        }
+    }
+
+    /// Requires the `u8` at `offset` to equal `value`.
+    pub fn u8_eq(offset: usize, value: u8) -> Self {
+        Self::Equals {
+            offset,
+            width: 1,
+            endidness: Endidness::Unknown,
+            value: value as u64,
+        }
+    }
+
+    numeric_constructors! {
+        u16, 2: u16_eq, u16_in_range, u16_aligned;
+        u32, 4: u32_eq, u32_in_range, u32_aligned;
+        u64, 8: u64_eq, u64_in_range, u64_aligned;
+    }
+
+    /// Checks whether or not the provided reader meets this constraint.
+    pub(crate) fn is_met_by<'r, B: BinReader<'r>>(&self, reader: &B) -> Result<bool> {
+        Ok(match self {
+            Self::Magic { offset, magic } => reader.subseq(*offset, magic.len())? == &magic[..],
+            Self::Equals {
+                offset,
+                width,
+                endidness,
+                value,
+            } => read_uint(reader, *offset, *width, *endidness)? == *value,
+            Self::InRange {
+                offset,
+                width,
+                endidness,
+                min,
+                max,
+            } => (*min..=*max).contains(&read_uint(reader, *offset, *width, *endidness)?),
+            Self::Aligned {
+                offset,
+                width,
+                endidness,
+                alignment,
+            } => {
+                *alignment != 0 && read_uint(reader, *offset, *width, *endidness)? % alignment == 0
+            }
+        })
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Magic { offset, magic } => write!(f, "magic {:02x?} at 0x{:x}", magic, offset),
+            Self::Equals {
+                offset,
+                width,
+                value,
+                ..
+            } => write!(f, "{}-byte value at 0x{:x} == {}", width, offset, value),
+            Self::InRange {
+                offset,
+                width,
+                min,
+                max,
+                ..
+            } => write!(
+                f,
+                "{}-byte value at 0x{:x} in {}..={}",
+                width, offset, min, max
+            ),
+            Self::Aligned {
+                offset,
+                width,
+                alignment,
+                ..
+            } => write!(
+                f,
+                "{}-byte value at 0x{:x} aligned to {}",
+                width, offset, alignment
+            ),
+        }
+    }
+}
+
+fn read_uint<'r, B: BinReader<'r>>(
+    reader: &B,
+    offset: usize,
+    width: usize,
+    endidness: Endidness,
+) -> Result<u64> {
+    if width == 0 || width > 8 {
+        return Err(Error::Other(format!(
+            "A width of {} is invalid; it must be between 1 and 8.",
+            width
+        )));
+    }
+    let mut buf = [0; 8];
+    match endidness {
+        _ if width == 1 => reader.bytes_at(offset, &mut buf[7..])?,
+        Endidness::Big => reader.bytes_at(offset, &mut buf[8 - width..])?,
+        Endidness::Little => {
+            reader.bytes_at(offset, &mut buf[..width])?;
+            return Ok(u64::from_le_bytes(buf));
+        }
+        Endidness::Unknown => return Err(Error::UnknownEndidness),
+    }
+    Ok(u64::from_be_bytes(buf))
+}
@@ -0,0 +1,12 @@
+/// A single command from a Mapbox Vector Tile geometry, as read by
+/// [`BinReader::read_geometry_commands`](crate::BinReader::read_geometry_commands). The
+/// coordinates have already had their deltas applied, so they are absolute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryCommand {
+    /// Moves the cursor to `(x, y)`, starting a new part of the geometry.
+    MoveTo(i32, i32),
+    /// Draws a line from the cursor to `(x, y)`.
+    LineTo(i32, i32),
+    /// Closes the current ring.
+    ClosePath,
+}
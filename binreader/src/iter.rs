@@ -1,12 +1,17 @@
-use crate::{BinReader, Endidness};
+use crate::{BinReader, Endidness, Error, Result};
 use std::marker::PhantomData;
 
+/// An iterator over the bytes of a borrowed [`BinReader`], created via
+/// [`BinReader::iter_bytes`]. It yields every byte from the reader's
+/// [`BinReader::current_offset`] (at the time it was created) up to the end of the data, without
+/// altering the reader's [`BinReader::current_offset`].
 pub struct BorrowedBinReaderIter<'a, 'r, B>
 where
     'r: 'a,
     B: BinReader<'r>,
 {
     reader: &'a B,
+    start: usize,
     cursor: usize,
     length: usize,
     endidness_override: Option<Endidness>,
@@ -21,6 +26,7 @@ where
     pub(crate) fn new(reader: &'a B, endidness_override: Option<Endidness>) -> Self {
         Self {
             reader,
+            start: reader.current_offset(),
             cursor: 0,
             length: reader.remaining(),
             endidness_override,
@@ -28,8 +34,112 @@ where
         }
     }
 
+    /// The endidness used when reading typed values: the override, if one was provided, or else
+    /// the reader's endidness.
     pub fn endidness(&self) -> Endidness {
         #[allow(clippy::or_fun_call)]
         self.endidness_override.unwrap_or(self.reader.endidness())
     }
 }
+
+impl<'a, 'r, B> Iterator for BorrowedBinReaderIter<'a, 'r, B>
+where
+    'r: 'a,
+    B: BinReader<'r>,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.cursor >= self.length {
+            return None;
+        }
+        let byte = self.reader.u8_at(self.start + self.cursor).ok()?;
+        self.cursor += 1;
+        Some(byte)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.length - self.cursor;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, 'r, B> ExactSizeIterator for BorrowedBinReaderIter<'a, 'r, B>
+where
+    'r: 'a,
+    B: BinReader<'r>,
+{
+}
+
+/// An iterator over consecutive integers in a borrowed [`BinReader`], created via methods such as
+/// [`BinReader::iter_u32`]. Each step reads an integer at the reader's
+/// [`BinReader::current_offset`] and then advances past it. Iteration stops once there isn't
+/// enough data left for a whole integer, so any partial trailing integer is left unread.
+pub struct NumberIter<'a, 'r, B, T>
+where
+    'r: 'a,
+    B: BinReader<'r>,
+{
+    reader: &'a B,
+    width: usize,
+    endidness: Endidness,
+    read_be: fn(&B, usize) -> Result<T>,
+    read_le: fn(&B, usize) -> Result<T>,
+    done: bool,
+    _marker: Option<&'r PhantomData<bool>>,
+}
+
+impl<'a, 'r, B, T> NumberIter<'a, 'r, B, T>
+where
+    'r: 'a,
+    B: BinReader<'r>,
+{
+    pub(crate) fn new(
+        reader: &'a B,
+        endidness_override: Option<Endidness>,
+        width: usize,
+        read_be: fn(&B, usize) -> Result<T>,
+        read_le: fn(&B, usize) -> Result<T>,
+    ) -> Self {
+        Self {
+            reader,
+            width,
+            endidness: endidness_override.unwrap_or_else(|| reader.endidness()),
+            read_be,
+            read_le,
+            done: false,
+            _marker: None,
+        }
+    }
+
+    /// The endidness the integers are read with.
+    pub fn endidness(&self) -> Endidness {
+        self.endidness
+    }
+}
+
+impl<'a, 'r, B, T> Iterator for NumberIter<'a, 'r, B, T>
+where
+    'r: 'a,
+    B: BinReader<'r>,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        if self.done || self.reader.remaining() < self.width {
+            return None;
+        }
+        let offset = self.reader.current_offset();
+        let value = match self.endidness {
+            Endidness::Big => (self.read_be)(self.reader, offset),
+            Endidness::Little => (self.read_le)(self.reader, offset),
+            Endidness::Unknown => Err(Error::UnknownEndidness),
+        }
+        .and_then(|value| {
+            self.reader.advance_by(self.width as isize)?;
+            Ok(value)
+        });
+        self.done = value.is_err();
+        Some(value)
+    }
+}
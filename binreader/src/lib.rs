@@ -13,41 +13,107 @@
 //!
 //! # Feature Flags
 //!
-//! As of right now, BinReader only has two feature flags:
+//! As of right now, BinReader has the following feature flags:
 //!
 //! - `nom-support` which allows [nom](https://github.com/Geal/nom) to parse from
 //!   BinReaders.
 //! - `memmap` which supports platform-independent memory mapped files (via the
 //!   [memmap2](https://github.com/RazrFalcon/memmap2-rs) crate).
+//! - `snappy` which supports decompressing Snappy data (via the
+//!   [snap](https://github.com/BurntSushi/rust-snappy) crate).
+//! - `lz4` which supports decompressing LZ4 data (via the
+//!   [lz4_flex](https://github.com/PSeitz/lz4_flex) crate).
+//! - `zlib` which supports decompressing zlib data (via the
+//!   [flate2](https://github.com/rust-lang/flate2-rs) crate).
+//! - `scroll` which allows [scroll](https://github.com/m4b/scroll) to read values from
+//!   BinReaders.
+//! - `chrono` which supports reading dates as [chrono](https://github.com/chronotope/chrono)
+//!   types.
+//! - `msgpack` which supports decoding MessagePack values (via the
+//!   [rmpv](https://github.com/3Hren/msgpack-rust) crate).
+//! - `tokio` which adds an asynchronous reader that reads from a
+//!   [tokio](https://tokio.rs) `AsyncRead`.
+//! - `bytemuck` which supports reading plain old data types directly (via the
+//!   [bytemuck](https://github.com/Lokathor/bytemuck) crate).
+//! - `crc` which supports verifying CRC-32 checksums (via the
+//!   [crc32fast](https://github.com/srijs/rust-crc32fast) crate).
 
 #![allow(clippy::needless_range_loop)]
 use binreader_macros::make_number_methods;
 use bytes::Bytes;
-use std::{borrow::Borrow, io, path::Path};
+use std::{
+    borrow::{Borrow, Cow},
+    convert::TryFrom,
+    io,
+    ops::Range,
+    path::Path,
+    str, string,
+};
 
 // Needed for some macros to work in this package.
 #[allow(unused_imports)]
 use crate as binreader;
 
-//pub mod iter;
+pub mod iter;
 pub mod util;
 
 #[macro_use]
 extern crate binreader_internal_macros;
 
+#[macro_use]
 mod readers;
 pub use readers::*;
+mod writers;
+pub use writers::*;
+
+mod regions;
+
+mod constraints;
+pub use constraints::Constraint;
+
+mod geometry;
+pub use geometry::GeometryCommand;
+
+mod bits;
+pub use bits::{BitOrder, BitReader};
+
+#[cfg(feature = "msgpack")]
+mod msgpack;
+#[cfg(feature = "msgpack")]
+pub use msgpack::MsgpackValue;
+
+#[cfg(feature = "tokio")]
+mod async_reader;
+#[cfg(feature = "tokio")]
+pub use async_reader::AsyncBinReader;
+
+#[cfg(any(feature = "snappy", feature = "lz4", feature = "zlib"))]
+mod compression;
+#[cfg(feature = "snappy")]
+pub use compression::SnappyFormat;
 
 #[cfg(test)]
 mod testing;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Endidness {
     Big,
     Little,
     Unknown,
 }
 
+/// A saved position within a reader, created via [`BinReader::bookmark`] and returned to via
+/// [`BinReader::restore`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bookmark(usize);
+
+impl Bookmark {
+    /// The offset that was saved.
+    pub fn offset(&self) -> usize {
+        self.0
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("An offset of 0x{0:x} is too small.")]
@@ -61,17 +127,31 @@ pub enum Error {
     UnknownEndidness,
     #[error("No more data left.")]
     NoMoreData,
+    #[error("Received invalid data at offset 0x{0:x}.")]
+    InvalidData(usize),
+    #[error("Found an incorrect magic number/signature at offset 0x{0:x}.")]
+    BadMagic(usize),
+    #[error("The stored checksum 0x{0:x} does not match the computed checksum 0x{1:x}.")]
+    /// ChecksumMismatch(stored checksum, computed checksum)
+    ChecksumMismatch(u64, u64),
+    #[error("A variable-length integer was too large for its type.")]
+    VarintTooLong,
+    #[error("The constraint {0} was violated.")]
+    ConstraintViolated(Constraint),
+    #[error("Element {0} was invalid: {1}")]
+    /// At(element index, error)
+    At(usize, Box<Error>),
+    #[error("Failed to decompress data: {0}")]
+    Decompression(String),
     #[error("{0}")]
     IoError(io::Error),
     #[error("{0}")]
     Other(String),
-    //#[error("Received invalid data.")]
-    //InvalidData,
 }
 
 impl From<Error> for io::Error {
     fn from(e: Error) -> Self {
-        io::Error::new(io::ErrorKind::Other, e)
+        io::Error::other(e)
     }
 }
 
@@ -81,6 +161,35 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<str::Utf8Error> for Error {
+    fn from(error: str::Utf8Error) -> Self {
+        Self::Other(error.to_string())
+    }
+}
+
+impl From<string::FromUtf8Error> for Error {
+    fn from(error: string::FromUtf8Error) -> Self {
+        Self::Other(error.to_string())
+    }
+}
+
+impl From<string::FromUtf16Error> for Error {
+    fn from(error: string::FromUtf16Error) -> Self {
+        Self::Other(error.to_string())
+    }
+}
+
+#[cfg(feature = "scroll")]
+impl From<scroll::Error> for Error {
+    fn from(error: scroll::Error) -> Self {
+        match error {
+            scroll::Error::TooBig { size, len } => Self::NotEnoughData(size, len),
+            scroll::Error::IO(error) => Self::IoError(error),
+            _ => Self::Other(error.to_string()),
+        }
+    }
+}
+
 pub type Result<V> = std::result::Result<V, Error>;
 
 /// The primary trait of this crate; a [`BinReader`] is designed to be a common interface between
@@ -157,12 +266,130 @@ where
     /// Changes the default endidness.
     fn change_endidness(&mut self, endidness: Endidness);
 
+    /// Turns on region tracking, after which regions labeled via [`BinReader::label_region`] are
+    /// recorded. Region tracking is off by default, and readers that don't support it can leave
+    /// this as a no-op.
+    fn enable_region_tracking(&self) {}
+
+    /// Labels the data between the provided starting and ending offsets with the given name. If
+    /// region tracking hasn't been enabled (see [`BinReader::enable_region_tracking`]), then this
+    /// does nothing.
+    fn label_region(&self, _name: &str, _start: usize, _end: usize) {}
+
+    /// All of the regions labeled so far, sorted by their starting offsets. Readers that don't
+    /// support region tracking always return an empty [`Vec`].
+    fn regions(&self) -> Vec<(String, Range<usize>)> {
+        Vec::new()
+    }
+
+    /// Turns on the monotonic check, after which any call to [`BinReader::advance_to`] or
+    /// [`BinReader::advance_by`] that would move the [`BinReader::current_offset`] backwards
+    /// returns an error. This is useful for formats that are guaranteed to only move forwards,
+    /// where a backwards jump means the data is corrupt (or malicious). Returning to a saved
+    /// offset (such as via [`BinReader::restore`] or [`BinReader::index_chunks`]) isn't affected.
+    /// The check is off by default, and readers that don't support it can leave this as a no-op.
+    fn enable_monotonic_check(&self) {}
+
+    /// Turns off the monotonic check (see [`BinReader::enable_monotonic_check`]).
+    fn disable_monotonic_check(&self) {}
+
+    /// Whether the monotonic check (see [`BinReader::enable_monotonic_check`]) is on. Readers that
+    /// don't support the check always return `false`.
+    fn monotonic_check_enabled(&self) -> bool {
+        false
+    }
+
     /// Sets the reader's [`BinReader::current_offset`].
     fn advance_to(&self, offset: usize) -> Result<()>;
 
     /// Alters the [`BinReader::current_offset`] by the given amount.
     fn advance_by(&self, num_bytes: isize) -> Result<()>;
 
+    /// Sets the [`BinReader::current_offset`] to the [`BinReader::upper_offset_limit`].
+    fn advance_to_end(&self) -> Result<()> {
+        self.advance_to(self.upper_offset_limit())
+    }
+
+    /// Sets the [`BinReader::current_offset`] to `back` bytes before the
+    /// [`BinReader::upper_offset_limit`], which is useful for reading trailers and footers. An
+    /// [`Error::OffsetTooSmall`] is returned if that would be before the
+    /// [`BinReader::lower_offset_limit`].
+    fn advance_from_end(&self, back: usize) -> Result<()> {
+        let offset = self
+            .upper_offset_limit()
+            .checked_sub(back)
+            .ok_or(Error::OffsetTooSmall(0))?;
+        self.advance_to(offset)
+    }
+
+    /// Advances the [`BinReader::current_offset`] by `num_bytes`. This is the same as
+    /// [`BinReader::advance_by`], but reads more clearly when skipping over data. If there are
+    /// fewer than `num_bytes` bytes left, then [`Error::NotEnoughData`] is returned.
+    fn skip(&self, num_bytes: usize) -> Result<()> {
+        if num_bytes > self.remaining() {
+            return Err(Error::NotEnoughData(num_bytes, self.remaining()));
+        }
+        self.advance_by(num_bytes as isize)
+    }
+
+    /// Advances the [`BinReader::current_offset`] to the next multiple of `alignment`, relative to
+    /// the [`BinReader::initial_offset`]. Nothing happens if the reader is already aligned, and an
+    /// [`Error::OffsetTooLarge`] is returned if aligning would move past the
+    /// [`BinReader::upper_offset_limit`].
+    fn align_to(&self, alignment: usize) -> Result<()> {
+        if alignment == 0 {
+            return Err(Error::Other(
+                "The alignment must be greater than 0.".to_string(),
+            ));
+        }
+        let misalignment = (self.current_offset() - self.initial_offset()) % alignment;
+        if misalignment == 0 {
+            return Ok(());
+        }
+        let offset = self
+            .current_offset()
+            .checked_add(alignment - misalignment)
+            .ok_or(Error::OffsetTooLarge(usize::MAX))?;
+        self.advance_to(offset)
+    }
+
+    /// Skips any padding needed to reach the next multiple of `alignment` (see
+    /// [`BinReader::align_to`]), then reads a `u32` using the default endidness, the same way a
+    /// C compiler lays out a struct field. The [`BinReader::current_offset`] is left unchanged if
+    /// the value can't be read.
+    fn read_aligned_field_u32(&self, alignment: usize) -> Result<u32> {
+        let bookmark = self.bookmark();
+        self.align_to(alignment)?;
+        let value = self.next_u32();
+        if value.is_err() {
+            self.restore(bookmark)?;
+        }
+        value
+    }
+
+    /// Saves the [`BinReader::current_offset`] so that it can be returned to later via
+    /// [`BinReader::restore`].
+    fn bookmark(&self) -> Bookmark {
+        Bookmark(self.current_offset())
+    }
+
+    /// Moves the [`BinReader::current_offset`] back to the position saved in the provided
+    /// [`Bookmark`]. This works even if the monotonic check (see
+    /// [`BinReader::enable_monotonic_check`]) is on.
+    fn restore(&self, bookmark: Bookmark) -> Result<()> {
+        util::rewind(self, bookmark.0)
+    }
+
+    /// Calls the provided function, then restores the [`BinReader::current_offset`] to what it
+    /// was beforehand, even if the function returned an error. This is useful for following
+    /// pointers to data stored elsewhere before resuming where parsing left off.
+    fn with_saved_offset<T, F: FnOnce(&Self) -> Result<T>>(&self, f: F) -> Result<T> {
+        let bookmark = self.bookmark();
+        let result = f(self);
+        self.restore(bookmark)?;
+        result
+    }
+
     /// Returns a [`Bytes`] object of the requested size containing the next n bytes (where n is
     /// the `num_bytes` parameter) and then advances the cursor by that much.
     fn next_n_bytes(&self, num_bytes: usize) -> Result<&[u8]> {
@@ -180,6 +407,48 @@ where
         self.range(self.current_offset(), self.upper_offset_limit())
     }
 
+    /// Returns an iterator over the bytes from the [`BinReader::current_offset`] to the end of the
+    /// data. Iterating does not alter the [`BinReader::current_offset`].
+    fn iter_bytes<'a>(&'a self) -> iter::BorrowedBinReaderIter<'a, 'r, Self>
+    where
+        'r: 'a,
+    {
+        iter::BorrowedBinReaderIter::new(self, None)
+    }
+
+    /// Returns an iterator that reads consecutive `u16`s starting at the
+    /// [`BinReader::current_offset`], advancing past each one as it's read. The provided
+    /// endidness is used if there is one, otherwise the default endidness is. Iteration stops
+    /// once fewer than 2 bytes are left.
+    fn iter_u16<'a>(&'a self, endidness: Option<Endidness>) -> iter::NumberIter<'a, 'r, Self, u16>
+    where
+        'r: 'a,
+    {
+        iter::NumberIter::new(self, endidness, 2, Self::u16_be_at, Self::u16_le_at)
+    }
+
+    /// Returns an iterator that reads consecutive `u32`s starting at the
+    /// [`BinReader::current_offset`], advancing past each one as it's read. The provided
+    /// endidness is used if there is one, otherwise the default endidness is. Iteration stops
+    /// once fewer than 4 bytes are left.
+    fn iter_u32<'a>(&'a self, endidness: Option<Endidness>) -> iter::NumberIter<'a, 'r, Self, u32>
+    where
+        'r: 'a,
+    {
+        iter::NumberIter::new(self, endidness, 4, Self::u32_be_at, Self::u32_le_at)
+    }
+
+    /// Returns an iterator that reads consecutive `u64`s starting at the
+    /// [`BinReader::current_offset`], advancing past each one as it's read. The provided
+    /// endidness is used if there is one, otherwise the default endidness is. Iteration stops
+    /// once fewer than 8 bytes are left.
+    fn iter_u64<'a>(&'a self, endidness: Option<Endidness>) -> iter::NumberIter<'a, 'r, Self, u64>
+    where
+        'r: 'a,
+    {
+        iter::NumberIter::new(self, endidness, 8, Self::u64_be_at, Self::u64_le_at)
+    }
+
     #[inline]
     /// The lowest valid offset that can be requested. By default, this is the same as
     /// [`BinReader::initial_offset`].
@@ -207,38 +476,44 @@ where
         self.upper_offset_limit() - self.current_offset()
     }
 
-    /// A helper method that validates an offset (mostly used by reader implementations).
+    /// A helper method that validates that `size` bytes can be read starting at `offset` (mostly
+    /// used by reader implementations).
     ///
     /// If the offset is valid, then `Ok(())` will be returned. Otherwise, the appropriate
     /// [`Error`] is returned (wrapped in `Err`, of course).
     fn validate_offset(&self, offset: usize, size: usize) -> Result<()> {
-        if size > 0 && self.is_empty() {
+        if size > 0 && offset == self.upper_offset_limit() {
             Err(Error::NoMoreData)
         } else if offset < self.lower_offset_limit() {
             Err(Error::OffsetTooSmall(offset))
         } else if offset > self.upper_offset_limit() {
             Err(Error::OffsetTooLarge(offset))
-        } else if offset > self.upper_offset_limit() - size {
-            Err(Error::NotEnoughData(size, self.remaining()))
+        } else if size > self.upper_offset_limit() - offset {
+            Err(Error::NotEnoughData(
+                size,
+                self.upper_offset_limit() - offset,
+            ))
         } else {
             Ok(())
         }
     }
 
     /// Takes an absolute offset and converts it to a relative offset, based off of the
-    /// [`BinReader::current_offset`].
+    /// [`BinReader::current_offset`]. Offsets before the [`BinReader::current_offset`] result in
+    /// an [`Error::OffsetTooSmall`].
     fn relative_offset(&self, abs_offset: usize) -> Result<usize> {
         self.validate_offset(abs_offset, 0)?;
-        Ok(abs_offset - self.current_offset())
+        abs_offset
+            .checked_sub(self.current_offset())
+            .ok_or(Error::OffsetTooSmall(abs_offset))
     }
 
     /// Returns `true` if the next bytes are the same as the ones provided.
     fn next_bytes_are(&self, prefix: &[u8]) -> Result<bool> {
         self.validate_offset(self.current_offset(), prefix.len())?;
-        let mut buf = Vec::with_capacity(prefix.len());
-        (0..buf.len()).for_each(|_| buf.push(0));
+        let mut buf = vec![0u8; prefix.len()];
         self.bytes_at(self.current_offset(), &mut buf)?;
-        Ok(prefix.iter().zip(buf.into_iter()).all(|(v1, v2)| *v1 == v2))
+        Ok(buf == prefix)
     }
 
     /// Fills the provided buffer with bytes, starting at the provided offset. This does not alter
@@ -258,10 +533,12 @@ where
         self.range(offset, offset + num_bytes)
     }
 
-    /// Returns a slice of the data between the provided starting and ending offsets.
+    /// Returns a slice of the data between the provided starting and ending offsets. If `end` is
+    /// before `start`, then an [`Error::OffsetTooSmall`] is returned.
     fn range(&self, start: usize, end: usize) -> Result<&[u8]> {
-        self.validate_offset(start, end - start)?;
-        Ok(&self.as_ref()[start..end])
+        let size = end.checked_sub(start).ok_or(Error::OffsetTooSmall(end))?;
+        self.validate_offset(start, size)?;
+        Ok(&self.as_ref()[start - self.initial_offset()..end - self.initial_offset()])
     }
 
     /// Fills the provided buffer with the next n bytes, where n is the length of the buffer. This
@@ -291,8 +568,11 @@ where
     }
 
     /// Gets the `u8` at the provided offset without altering the [`BinReader::current_offset`].
+    /// Any offset between the [`BinReader::initial_offset`] and the
+    /// [`BinReader::upper_offset_limit`] can be read, including ones before the
+    /// [`BinReader::current_offset`].
     fn u8_at(&self, offset: usize) -> Result<u8> {
-        self.validate_offset(offset, 0)?;
+        self.validate_offset(offset, 1)?;
         Ok(self.as_ref()[offset - self.initial_offset()])
     }
 
@@ -508,11 +788,11 @@ where
     }
 
     #[inline]
-    fn slice_reader(&self, start: usize, end: usize) -> Result<SliceRefBinReader> {
+    fn slice_reader(&self, start: usize, end: usize) -> Result<SliceRefBinReader<'_>> {
         SliceRefBinReader::from_slice(self.range(start, end)?, self.endidness())
     }
 
-    fn next_n_bytes_as_reader(&self, num_bytes: usize) -> Result<SliceRefBinReader> {
+    fn next_n_bytes_as_reader(&self, num_bytes: usize) -> Result<SliceRefBinReader<'_>> {
         let res = SliceRefBinReader::from_slice(
             self.subseq(self.current_offset(), num_bytes)?,
             self.endidness(),
@@ -521,7 +801,10 @@ where
         Ok(res)
     }
 
-    fn next_n_bytes_as_reader_retain_offset(&self, num_bytes: usize) -> Result<SliceRefBinReader> {
+    fn next_n_bytes_as_reader_retain_offset(
+        &self,
+        num_bytes: usize,
+    ) -> Result<SliceRefBinReader<'_>> {
         let res = SliceRefBinReader::from_slice_with_offset(
             self.subseq(self.current_offset(), num_bytes)?,
             self.current_offset(),
@@ -537,18 +820,1656 @@ where
         start: usize,
         offset: usize,
         end: usize,
-    ) -> Result<SliceRefBinReader> {
+    ) -> Result<SliceRefBinReader<'_>> {
         SliceRefBinReader::from_slice_with_offset(self.range(start, end)?, offset, self.endidness())
     }
 
     #[inline]
-    fn slice_reader_retain_offset(&self, start: usize, end: usize) -> Result<SliceRefBinReader> {
+    fn slice_reader_retain_offset(
+        &self,
+        start: usize,
+        end: usize,
+    ) -> Result<SliceRefBinReader<'_>> {
         SliceRefBinReader::from_slice_with_offset(
             self.range(start, end)?,
             self.current_offset(),
             self.endidness(),
         )
     }
+
+    #[cfg(feature = "snappy")]
+    /// Decompresses the next `compressed_len` bytes as Snappy data stored in the given
+    /// [`SnappyFormat`], returning a new [`RandomAccessBinReader`] over the decompressed data. The
+    /// cursor is only advanced if decompression succeeds.
+    fn snappy_next_n(
+        &self,
+        compressed_len: usize,
+        format: SnappyFormat,
+    ) -> Result<RandomAccessBinReader> {
+        let data = compression::snappy_decompress(
+            self.subseq(self.current_offset(), compressed_len)?,
+            format,
+        )?;
+        self.advance_by(compressed_len as isize)?;
+        RandomAccessBinReader::from_bytes(data, self.endidness())
+    }
+
+    #[cfg(feature = "lz4")]
+    /// Decompresses the next `compressed_len` bytes as a raw LZ4 block that decompresses to
+    /// exactly `decompressed_len` bytes, returning a new [`RandomAccessBinReader`] over the
+    /// decompressed data. The cursor is only advanced if decompression succeeds.
+    fn lz4_next_n(
+        &self,
+        compressed_len: usize,
+        decompressed_len: usize,
+    ) -> Result<RandomAccessBinReader> {
+        let data = compression::lz4_block_decompress(
+            self.subseq(self.current_offset(), compressed_len)?,
+            decompressed_len,
+        )?;
+        self.advance_by(compressed_len as isize)?;
+        RandomAccessBinReader::from_bytes(data, self.endidness())
+    }
+
+    #[cfg(feature = "lz4")]
+    /// Decompresses the next `compressed_len` bytes as data in the LZ4 frame format, returning a
+    /// new [`RandomAccessBinReader`] over the decompressed data. The cursor is only advanced if
+    /// decompression succeeds.
+    fn lz4_frame_next_n(&self, compressed_len: usize) -> Result<RandomAccessBinReader> {
+        let data =
+            compression::lz4_frame_decompress(self.subseq(self.current_offset(), compressed_len)?)?;
+        self.advance_by(compressed_len as isize)?;
+        RandomAccessBinReader::from_bytes(data, self.endidness())
+    }
+
+    #[cfg(feature = "zlib")]
+    /// Decompresses the next `compressed_len` bytes as zlib data, returning a new
+    /// [`RandomAccessBinReader`] over the decompressed data. The cursor is only advanced if
+    /// decompression succeeds.
+    fn zlib_next_n(&self, compressed_len: usize) -> Result<RandomAccessBinReader> {
+        let data =
+            compression::zlib_decompress(self.subseq(self.current_offset(), compressed_len)?)?;
+        self.advance_by(compressed_len as isize)?;
+        RandomAccessBinReader::from_bytes(data, self.endidness())
+    }
+
+    /// Reads a compressed block made up of a compression type byte, a `u32` length (using the
+    /// default endidness) and then that many bytes of data, and returns a
+    /// [`RandomAccessBinReader`] over the decompressed data. The type byte is one of:
+    ///
+    /// - `0`: the data is stored uncompressed.
+    /// - `1`: zlib (requires the `zlib` feature).
+    /// - `2`: the LZ4 frame format (requires the `lz4` feature).
+    /// - `3`: the raw Snappy format (requires the `snappy` feature).
+    ///
+    /// An unknown type byte results in an [`Error::InvalidData`], and a type whose feature isn't
+    /// enabled results in an [`Error::Other`]. The cursor is only advanced if the block could be
+    /// decompressed.
+    fn next_typed_compressed_block(&self) -> Result<RandomAccessBinReader> {
+        let offset = self.current_offset();
+        let compression_type = self.u8_at(offset)?;
+        let len = self.u32_at(offset + 1)? as usize;
+        let data = self.subseq(offset + 5, len)?;
+        let decompressed = match compression_type {
+            0 => Bytes::copy_from_slice(data),
+            #[cfg(feature = "zlib")]
+            1 => compression::zlib_decompress(data)?,
+            #[cfg(feature = "lz4")]
+            2 => compression::lz4_frame_decompress(data)?,
+            #[cfg(feature = "snappy")]
+            3 => compression::snappy_decompress(data, SnappyFormat::Raw)?,
+            #[allow(unreachable_patterns)]
+            1..=3 => {
+                return Err(Error::Other(format!(
+                    "Support for compression type {} is not enabled.",
+                    compression_type
+                )))
+            }
+            _ => return Err(Error::InvalidData(offset)),
+        };
+        self.advance_to(offset + 5 + len)?;
+        RandomAccessBinReader::from_bytes(decompressed, self.endidness())
+    }
+
+    /// Reads the next `len` bytes as ASCII hexadecimal digits (upper or lower case) and parses
+    /// them into a `u64`. If any of the bytes aren't hex digits, or the value doesn't fit into a
+    /// `u64`, then [`Error::InvalidData`] is returned with the offending offset and the
+    /// [`BinReader::current_offset`] is left unchanged.
+    fn read_hex_field(&self, len: usize) -> Result<u64> {
+        let start = self.current_offset();
+        let mut value: u64 = 0;
+        for (i, byte) in self.subseq(start, len)?.iter().enumerate() {
+            let digit = (*byte as char)
+                .to_digit(16)
+                .ok_or(Error::InvalidData(start + i))?;
+            value = value
+                .checked_mul(16)
+                .map(|v| v + digit as u64)
+                .ok_or(Error::InvalidData(start + i))?;
+        }
+        self.advance_by(len as isize)?;
+        Ok(value)
+    }
+
+    /// Reads the next `num_bytes` bytes and returns them as a lowercase hexadecimal string, which
+    /// is handy for displaying hashes and identifiers.
+    #[inline]
+    fn next_hex_string(&self, num_bytes: usize) -> Result<String> {
+        self.next_hex_string_with(num_bytes, false)
+    }
+
+    /// Reads the next `num_bytes` bytes and returns them as a hexadecimal string, using uppercase
+    /// digits if `uppercase` is `true`.
+    fn next_hex_string_with(&self, num_bytes: usize, uppercase: bool) -> Result<String> {
+        let string = self
+            .next_n_bytes(num_bytes)?
+            .iter()
+            .map(|byte| {
+                if uppercase {
+                    format!("{:02X}", byte)
+                } else {
+                    format!("{:02x}", byte)
+                }
+            })
+            .collect();
+        Ok(string)
+    }
+
+    /// Advances the [`BinReader::current_offset`] to the start of the next occurrence of
+    /// `sync_pattern`, discarding everything before it, and returns how many bytes were discarded.
+    ///
+    /// If the pattern can't be found, then the cursor is advanced to the end of the reader. After
+    /// that, if `error_if_missing` is `true` then [`Error::NoMoreData`] is returned; otherwise the
+    /// number of bytes discarded is returned like normal.
+    fn sync_to(&self, sync_pattern: &[u8], error_if_missing: bool) -> Result<usize> {
+        let found = if sync_pattern.is_empty() {
+            Some(0)
+        } else {
+            self.get_remaining()?
+                .windows(sync_pattern.len())
+                .position(|window| window == sync_pattern)
+        };
+        match found {
+            Some(discarded) => {
+                self.advance_by(discarded as isize)?;
+                Ok(discarded)
+            }
+            None => {
+                let discarded = self.remaining();
+                self.advance_to(self.upper_offset_limit())?;
+                if error_if_missing {
+                    Err(Error::NoMoreData)
+                } else {
+                    Ok(discarded)
+                }
+            }
+        }
+    }
+
+    /// Searches for the first occurrence of `needle` at or after the
+    /// [`BinReader::current_offset`], returning its absolute offset (or `None` if it can't be
+    /// found). This does not alter the [`BinReader::current_offset`]. An empty needle results in
+    /// an [`Error::Other`].
+    fn find_bytes(&self, needle: &[u8]) -> Result<Option<usize>> {
+        if needle.is_empty() {
+            return Err(Error::Other(
+                "Cannot search for an empty needle.".to_string(),
+            ));
+        }
+        Ok(memchr::memmem::find(self.get_remaining()?, needle)
+            .map(|pos| self.current_offset() + pos))
+    }
+
+    /// Advances the [`BinReader::current_offset`] to the first occurrence of `needle` (see
+    /// [`BinReader::find_bytes`]). If it can't be found, then [`Error::NoMoreData`] is returned
+    /// and the [`BinReader::current_offset`] is left alone.
+    fn seek_to_bytes(&self, needle: &[u8]) -> Result<()> {
+        let offset = self.find_bytes(needle)?.ok_or(Error::NoMoreData)?;
+        self.advance_to(offset)
+    }
+
+    /// Computes the 16-bit one's complement internet checksum (as described in RFC 1071) of the
+    /// data between the provided starting and ending offsets. If the range has an odd length, it's
+    /// padded with a single zero byte.
+    fn inet_checksum(&self, start: usize, end: usize) -> Result<u16> {
+        let mut sum: u32 = 0;
+        for chunk in self.range(start, end)?.chunks(2) {
+            let word = match chunk {
+                [high, low] => u16::from_be_bytes([*high, *low]),
+                [high] => u16::from_be_bytes([*high, 0]),
+                _ => unreachable!(),
+            };
+            sum += word as u32;
+            sum = (sum & 0xffff) + (sum >> 16);
+        }
+        Ok(!(sum as u16))
+    }
+
+    /// Computes a simple checksum by XORing together all of the bytes between the provided
+    /// starting and ending offsets, such as the one used by NMEA sentences.
+    fn xor_checksum(&self, start: usize, end: usize) -> Result<u8> {
+        Ok(self
+            .range(start, end)?
+            .iter()
+            .fold(0, |sum, byte| sum ^ byte))
+    }
+
+    /// Checks whether or not the XOR checksum (see [`BinReader::xor_checksum`]) of the data in
+    /// `data_range` matches the byte at `checksum_offset`.
+    fn verify_xor_checksum(
+        &self,
+        data_range: Range<usize>,
+        checksum_offset: usize,
+    ) -> Result<bool> {
+        Ok(self.xor_checksum(data_range.start, data_range.end)? == self.u8_at(checksum_offset)?)
+    }
+
+    #[cfg(feature = "crc")]
+    /// Reads a record made up of `payload_len` bytes of payload followed by the CRC-32 (using the
+    /// default endidness) of that payload, and returns the payload. If the stored CRC doesn't
+    /// match the payload, then an [`Error::ChecksumMismatch`] is returned. The
+    /// [`BinReader::current_offset`] is only advanced past the record if the CRC matches.
+    fn read_crc_protected_record_u32(&self, payload_len: usize) -> Result<&[u8]> {
+        let offset = self.current_offset();
+        let payload = self.subseq(offset, payload_len)?;
+        let stored = self.u32_at(offset + payload_len)?;
+        let computed = crc32fast::hash(payload);
+        if stored != computed {
+            return Err(Error::ChecksumMismatch(stored as u64, computed as u64));
+        }
+        self.advance_by(payload_len as isize + 4)?;
+        Ok(payload)
+    }
+
+    #[cfg(feature = "crc")]
+    /// Functions the same as [`BinReader::next_n_bytes`], except it also returns the CRC-32 of the
+    /// bytes that were read, so that they can be checked against a checksum without a second
+    /// pass over the data.
+    fn next_n_bytes_checked(&self, num_bytes: usize) -> Result<(&[u8], u32)> {
+        let data = self.next_n_bytes(num_bytes)?;
+        Ok((data, crc32fast::hash(data)))
+    }
+
+    /// Reads a `u32` length using the default endidness, then returns a [`SliceRefBinReader`]
+    /// over exactly that many of the following bytes. The sub-reader's initial offset is the
+    /// offset of the first byte after the length, and the [`BinReader::current_offset`] is
+    /// advanced past the whole block.
+    fn next_sized_reader_u32(&self) -> Result<SliceRefBinReader<'_>> {
+        let len = self.u32_at(self.current_offset())? as usize;
+        let start = self.current_offset() + 4;
+        let reader = SliceRefBinReader::from_slice_with_offset(
+            self.subseq(start, len)?,
+            start,
+            self.endidness(),
+        )?;
+        self.advance_to(start + len)?;
+        Ok(reader)
+    }
+
+    /// Enters a `u32` length-delimited frame, returning a sub-reader bounded to the frame. This is
+    /// the same as [`BinReader::next_sized_reader_u32`], and since the returned reader is itself a
+    /// [`BinReader`], nested frames can be descended into by calling this on it.
+    #[inline]
+    fn enter_frame_u32(&self) -> Result<SliceRefBinReader<'_>> {
+        self.next_sized_reader_u32()
+    }
+
+    /// Reads a `u32` length using the default endidness, then skips over that many of the
+    /// following bytes, returning the absolute range that was skipped. This allows a section to
+    /// be parsed later on (such as via [`BinReader::slice_reader`]) without having to re-read its
+    /// length.
+    fn skip_sized_u32(&self) -> Result<Range<usize>> {
+        let len = self.u32_at(self.current_offset())? as usize;
+        let start = self.current_offset() + 4;
+        self.validate_offset(start, len)?;
+        self.advance_to(start + len)?;
+        Ok(start..start + len)
+    }
+
+    #[cfg(feature = "scroll")]
+    /// Reads a `T` from the data at the [`BinReader::current_offset`] using [`scroll`] and the
+    /// reader's endidness, then advances the [`BinReader::current_offset`] by however many bytes
+    /// were read. If the current endidness is [`Endidness::Unknown`], then an error is returned.
+    fn scroll_gread<'a, T>(&'a self) -> Result<T>
+    where
+        T: scroll::ctx::TryFromCtx<'a, scroll::Endian, Error = scroll::Error>,
+    {
+        match self.endidness() {
+            Endidness::Big => self.scroll_gread_with(scroll::BE),
+            Endidness::Little => self.scroll_gread_with(scroll::LE),
+            Endidness::Unknown => Err(Error::UnknownEndidness),
+        }
+    }
+
+    #[cfg(feature = "scroll")]
+    /// Functions the same as [`BinReader::scroll_gread`], except the provided context is passed
+    /// to [`scroll`] instead of the reader's endidness.
+    fn scroll_gread_with<'a, T, C>(&'a self, ctx: C) -> Result<T>
+    where
+        T: scroll::ctx::TryFromCtx<'a, C, Error = scroll::Error>,
+        C: Copy,
+    {
+        let mut read = 0;
+        let value = scroll::Pread::gread_with(self.get_remaining()?, &mut read, ctx)?;
+        self.advance_by(read as isize)?;
+        Ok(value)
+    }
+
+    /// Reads an unsigned LEB128 variable-length integer starting at the
+    /// [`BinReader::current_offset`], advancing past each byte as it's read. If the value is too
+    /// large to fit into a `u64`, then [`Error::VarintTooLong`] is returned.
+    fn next_uleb128(&self) -> Result<u64> {
+        util::decode_uleb128(|| self.next_u8())
+    }
+
+    /// Reads an unsigned LEB128 variable-length integer (see [`BinReader::next_uleb128`]) starting
+    /// at the provided offset without altering the [`BinReader::current_offset`].
+    fn uleb128_at(&self, offset: usize) -> Result<u64> {
+        let mut offset = offset;
+        util::decode_uleb128(|| {
+            offset += 1;
+            self.u8_at(offset - 1)
+        })
+    }
+
+    /// Reads a signed LEB128 variable-length integer starting at the
+    /// [`BinReader::current_offset`], advancing past each byte as it's read. If the value is too
+    /// large to fit into an `i64`, then [`Error::VarintTooLong`] is returned.
+    fn next_sleb128(&self) -> Result<i64> {
+        util::decode_sleb128(|| self.next_u8())
+    }
+
+    /// Reads a signed LEB128 variable-length integer (see [`BinReader::next_sleb128`]) starting at
+    /// the provided offset without altering the [`BinReader::current_offset`].
+    fn sleb128_at(&self, offset: usize) -> Result<i64> {
+        let mut offset = offset;
+        util::decode_sleb128(|| {
+            offset += 1;
+            self.u8_at(offset - 1)
+        })
+    }
+
+    /// Reads a DEX style `sleb128p1`, which is a signed LEB128 (see [`BinReader::next_sleb128`])
+    /// that holds the actual value plus one. This is how DEX files store values that are
+    /// usually `-1`.
+    fn next_sleb128p1(&self) -> Result<i64> {
+        self.next_sleb128()?
+            .checked_sub(1)
+            .ok_or(Error::VarintTooLong)
+    }
+
+    /// Reads `count` consecutive unsigned LEB128 values (see [`BinReader::next_uleb128`]),
+    /// advancing past all of them.
+    fn read_uleb128_vec(&self, count: usize) -> Result<Vec<u64>> {
+        let mut values = Vec::with_capacity(count.min(self.remaining()));
+        for _ in 0..count {
+            values.push(self.next_uleb128()?);
+        }
+        Ok(values)
+    }
+
+    /// Reads `u32`s using the default endidness for as long as `pred` returns `true` for them,
+    /// stopping at the first value it rejects or at the end of the data. The rejected value is
+    /// only peeked at, so the [`BinReader::current_offset`] is left pointing at it. If the data
+    /// ends partway through a value, then an error is returned and nothing is consumed.
+    fn read_u32_while<F: Fn(u32) -> bool>(&self, pred: F) -> Result<Vec<u32>> {
+        let mut offset = self.current_offset();
+        let mut values = Vec::new();
+        while offset < self.upper_offset_limit() {
+            let value = self.u32_at(offset)?;
+            if !pred(value) {
+                break;
+            }
+            values.push(value);
+            offset += 4;
+        }
+        self.advance_to(offset)?;
+        Ok(values)
+    }
+
+    /// Reads a WebAssembly style section header, which is a `u8` section ID followed by the
+    /// section's size as an unsigned LEB128 (see [`BinReader::next_uleb128`]), and returns the ID
+    /// along with a [`SliceRefBinReader`] over the section's contents. The sub-reader's initial
+    /// offset is the offset of the first byte of the contents, and the
+    /// [`BinReader::current_offset`] is only advanced past the section if the whole thing could
+    /// be read.
+    fn next_wasm_section(&self) -> Result<(u8, SliceRefBinReader<'_>)> {
+        let id = self.u8_at(self.current_offset())?;
+        let mut start = self.current_offset() + 1;
+        let size = util::decode_uleb128(|| {
+            start += 1;
+            self.u8_at(start - 1)
+        })?;
+        let size = usize::try_from(size).map_err(|_| Error::VarintTooLong)?;
+        let reader = SliceRefBinReader::from_slice_with_offset(
+            self.subseq(start, size)?,
+            start,
+            self.endidness(),
+        )?;
+        self.advance_to(start + size)?;
+        Ok((id, reader))
+    }
+
+    /// Reads a table of `count` offsets stored as unsigned LEB128 deltas from the previous entry,
+    /// with the first delta being relative to `base`, and returns the resulting absolute offsets.
+    /// Each offset has to be within the reader's bounds, otherwise the appropriate error is
+    /// returned. The [`BinReader::current_offset`] is only advanced if the whole table is valid.
+    fn read_delta_offset_table(&self, count: usize, base: usize) -> Result<Vec<usize>> {
+        let mut cursor = self.current_offset();
+        let mut offsets = Vec::with_capacity(count.min(self.remaining()));
+        let mut offset = base;
+        for _ in 0..count {
+            let delta = util::decode_uleb128(|| {
+                cursor += 1;
+                self.u8_at(cursor - 1)
+            })?;
+            offset = usize::try_from(delta)
+                .ok()
+                .and_then(|delta| offset.checked_add(delta))
+                .ok_or(Error::OffsetTooLarge(usize::MAX))?;
+            self.validate_offset(offset, 0)?;
+            offsets.push(offset);
+        }
+        self.advance_to(cursor)?;
+        Ok(offsets)
+    }
+
+    /// Reads `count` unsigned integers, each `bit_width` bits wide, that are packed together
+    /// least-significant bit first (the same way Parquet packs them). Afterwards, the
+    /// [`BinReader::current_offset`] is advanced to the first byte boundary after the packed
+    /// values. The `bit_width` has to be between `1` and `64`.
+    fn read_bitpacked(&self, bit_width: u8, count: usize) -> Result<Vec<u64>> {
+        if bit_width == 0 || bit_width > 64 {
+            return Err(Error::Other(format!(
+                "A bit width of {} is invalid; it must be between 1 and 64.",
+                bit_width
+            )));
+        }
+        let num_bytes = (bit_width as usize)
+            .checked_mul(count)
+            .map(|bits| bits.div_ceil(8))
+            .ok_or(Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        let data = self.subseq(self.current_offset(), num_bytes)?;
+        // Now that the data's been validated, count can be no more than the number of bits in it.
+        let mut values = Vec::with_capacity(count);
+        let mut bit_pos = 0;
+        for _ in 0..count {
+            let mut value = 0;
+            for i in 0..bit_width as usize {
+                let bit = (data[bit_pos / 8] >> (bit_pos % 8)) & 1;
+                value |= (bit as u64) << i;
+                bit_pos += 1;
+            }
+            values.push(value);
+        }
+        self.advance_by(num_bytes as isize)?;
+        Ok(values)
+    }
+
+    /// How many whole records of `record_size` bytes fit in the data left after the
+    /// [`BinReader::current_offset`]. A `record_size` of `0` results in `0`.
+    #[inline]
+    fn remaining_record_count(&self, record_size: usize) -> usize {
+        self.remaining().checked_div(record_size).unwrap_or(0)
+    }
+
+    /// Computes how many elements of `element_size` bytes are left in a structure that's
+    /// `total_size` bytes long, based off of how much of it has already been read (i.e. the
+    /// distance from the [`BinReader::initial_offset`] to the [`BinReader::current_offset`]).
+    ///
+    /// An error is returned if more than `total_size` bytes have already been read, if
+    /// `element_size` is zero, or if the leftover bytes don't evenly divide into elements (unless
+    /// `allow_remainder` is `true`, in which case the remainder is ignored).
+    fn implied_count(
+        &self,
+        total_size: usize,
+        element_size: usize,
+        allow_remainder: bool,
+    ) -> Result<usize> {
+        let read = self.current_offset() - self.initial_offset();
+        let left = total_size.checked_sub(read).ok_or_else(|| {
+            Error::Other(format!(
+                "Already read {} bytes, which is more than the total size of {}.",
+                read, total_size
+            ))
+        })?;
+        if element_size == 0 {
+            Err(Error::Other("The element size cannot be zero.".to_string()))
+        } else if !allow_remainder && left % element_size != 0 {
+            Err(Error::Other(format!(
+                "{} bytes cannot be evenly divided into elements of {} bytes.",
+                left, element_size
+            )))
+        } else {
+            Ok(left / element_size)
+        }
+    }
+
+    /// Reads a 4 byte SMPTE timecode, where each byte is a binary-coded decimal, returning it as
+    /// `(hours, minutes, seconds, frames)`. If any of the nibbles isn't a valid decimal digit,
+    /// then [`Error::InvalidData`] is returned and the [`BinReader::current_offset`] is left
+    /// unchanged.
+    fn next_smpte_timecode(&self) -> Result<(u8, u8, u8, u8)> {
+        let start = self.current_offset();
+        let mut fields = [0; 4];
+        for (i, byte) in self.subseq(start, 4)?.iter().enumerate() {
+            let (high, low) = (byte >> 4, byte & 0x0f);
+            if high > 9 || low > 9 {
+                return Err(Error::InvalidData(start + i));
+            }
+            fields[i] = high * 10 + low;
+        }
+        self.advance_by(4)?;
+        Ok((fields[0], fields[1], fields[2], fields[3]))
+    }
+
+    /// Reads `count` front coded UTF-8 strings. Each entry consists of the length of the prefix
+    /// it shares with the previous string (as an unsigned LEB128), followed by the length of the
+    /// rest of the string (also an unsigned LEB128) and then the rest of the string itself.
+    ///
+    /// If an entry's shared prefix is longer than the previous string, then
+    /// [`Error::InvalidData`] is returned with the offset of that entry.
+    fn read_front_coded_strings(&self, count: usize) -> Result<Vec<String>> {
+        let mut strings = Vec::with_capacity(count.min(self.remaining()));
+        let mut previous: &[u8] = &[];
+        for _ in 0..count {
+            let entry_offset = self.current_offset();
+            let prefix_len = self.next_uleb128()? as usize;
+            if prefix_len > previous.len() {
+                return Err(Error::InvalidData(entry_offset));
+            }
+            let suffix_len = self.next_uleb128()? as usize;
+            let mut string = previous[..prefix_len].to_vec();
+            string.extend_from_slice(self.next_n_bytes(suffix_len)?);
+            strings.push(String::from_utf8(string)?);
+            previous = strings.last().unwrap().as_bytes();
+        }
+        Ok(strings)
+    }
+
+    /// Reads `count` consecutive `(key, value)` pairs of `u32`s using the default endidness. If
+    /// the current endidness is [`Endidness::Unknown`], then an error is returned.
+    fn read_kv_pairs_u32(&self, count: usize) -> Result<Vec<(u32, u32)>> {
+        let num_bytes = count
+            .checked_mul(8)
+            .ok_or(Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        self.validate_offset(self.current_offset(), num_bytes)?;
+        (0..count)
+            .map(|_| Ok((self.next_u32()?, self.next_u32()?)))
+            .collect()
+    }
+
+    /// Reads `count` key-length-value entries, each of which consists of a `u8` key length, the
+    /// UTF-8 key itself, a `u16` value length (using the default endidness) and then the value's
+    /// bytes. The [`BinReader::current_offset`] is only advanced if every entry could be read.
+    fn read_klv_map(&self, count: usize) -> Result<Vec<(String, Vec<u8>)>> {
+        let mut offset = self.current_offset();
+        let mut entries = Vec::with_capacity(count.min(self.remaining()));
+        for _ in 0..count {
+            let key_len = self.u8_at(offset)? as usize;
+            let key = str::from_utf8(self.subseq(offset + 1, key_len)?)?.to_string();
+            offset += 1 + key_len;
+            let value_len = self.u16_at(offset)? as usize;
+            entries.push((key, self.subseq(offset + 2, value_len)?.to_vec()));
+            offset += 2 + value_len;
+        }
+        self.advance_to(offset)?;
+        Ok(entries)
+    }
+
+    /// Reads `count` zigzag encoded unsigned LEB128 values (see [`util::zigzag_decode`]) and
+    /// treats each one as a delta from the previous value, starting with `start`. The
+    /// accumulated values are returned. If a delta doesn't fit into an `i32`, then
+    /// [`Error::VarintTooLong`] is returned, and if the accumulated value overflows, then
+    /// [`Error::InvalidData`] is returned.
+    fn read_zigzag_delta_i32(&self, count: usize, start: i32) -> Result<Vec<i32>> {
+        let mut values = Vec::with_capacity(count.min(self.remaining()));
+        let mut current = start;
+        for _ in 0..count {
+            let offset = self.current_offset();
+            let delta = i32::try_from(util::zigzag_decode(self.next_uleb128()?))
+                .map_err(|_| Error::VarintTooLong)?;
+            current = current
+                .checked_add(delta)
+                .ok_or(Error::InvalidData(offset))?;
+            values.push(current);
+        }
+        Ok(values)
+    }
+
+    /// Reads an unsigned LEB128 length (see [`BinReader::next_uleb128`]) followed by that many
+    /// bytes of UTF-8 data, returning the data as a [`String`]. If the data isn't valid UTF-8,
+    /// then an [`Error::Other`] is returned and the cursor is left just after the length.
+    fn next_varint_string(&self) -> Result<String> {
+        let len = self.next_uleb128()? as usize;
+        let string = str::from_utf8(self.subseq(self.current_offset(), len)?)?.to_string();
+        self.advance_by(len as isize)?;
+        Ok(string)
+    }
+
+    /// Checks each of the provided [`Constraint`]s in order, returning the first one that isn't
+    /// met wrapped in an [`Error::ConstraintViolated`]. If a constraint's data can't be read, then
+    /// that error is returned instead. This does not alter the [`BinReader::current_offset`].
+    fn check(&self, constraints: &[Constraint]) -> Result<()> {
+        for constraint in constraints {
+            if !constraint.is_met_by(self)? {
+                return Err(Error::ConstraintViolated(constraint.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a two's-complement integer that's `width` bytes wide (between `1` and `8`) at the
+    /// provided offset using the provided endidness, sign-extending it into an `i64`. This is
+    /// useful for oddly-sized integers such as `i24`s or `i48`s.
+    fn int_at_with(&self, offset: usize, width: usize, endidness: Endidness) -> Result<i64> {
+        if !(1..=8).contains(&width) {
+            return Err(Error::Other(format!(
+                "A width of {} bytes is invalid; it must be between 1 and 8.",
+                width
+            )));
+        }
+        let bytes = self.subseq(offset, width)?;
+        let value = match endidness {
+            Endidness::Unknown if width > 1 => return Err(Error::UnknownEndidness),
+            Endidness::Little => bytes
+                .iter()
+                .rev()
+                .fold(0, |value, byte| (value << 8) | *byte as u64),
+            _ => bytes
+                .iter()
+                .fold(0, |value, byte| (value << 8) | *byte as u64),
+        };
+        let shift = 64 - width * 8;
+        Ok(((value << shift) as i64) >> shift)
+    }
+
+    /// Parses `count` elements using the provided function, but only after making sure that
+    /// `count` is no more than `max_count`. This is meant to guard against untrusted count fields
+    /// causing huge allocations, so nothing is allocated or read if the count is over the cap.
+    fn read_capped_array<T, F: Fn(&Self) -> Result<T>>(
+        &self,
+        count: usize,
+        max_count: usize,
+        parse: F,
+    ) -> Result<Vec<T>> {
+        if count > max_count {
+            return Err(Error::Other(format!(
+                "A count of {} is larger than the maximum of {}.",
+                count, max_count
+            )));
+        }
+        let mut values = Vec::with_capacity(count.min(self.remaining()));
+        for _ in 0..count {
+            values.push(parse(self)?);
+        }
+        Ok(values)
+    }
+
+    /// Reads a bitmap of `field_count` bits (padded out to a whole number of bytes) and returns
+    /// whether or not each field is present, with the most significant bit of each byte being
+    /// the first field. Afterwards, the [`BinReader::current_offset`] is advanced past the bitmap.
+    fn read_presence_bitmap(&self, field_count: usize) -> Result<Vec<bool>> {
+        let num_bytes = field_count.div_ceil(8);
+        let data = self.subseq(self.current_offset(), num_bytes)?;
+        let present = (0..field_count)
+            .map(|i| data[i / 8] & (0x80 >> (i % 8)) != 0)
+            .collect();
+        self.advance_by(num_bytes as isize)?;
+        Ok(present)
+    }
+
+    /// Returns whether or not the provided bit (between `0` and `7`) of the byte at `byte_offset`
+    /// is set, where `order` determines whether bit `0` is the most or least significant bit.
+    /// This does not alter the [`BinReader::current_offset`].
+    fn flag_at(&self, byte_offset: usize, bit: u8, order: BitOrder) -> Result<bool> {
+        if bit > 7 {
+            return Err(Error::Other(format!(
+                "A bit index of {} is invalid; it must be between 0 and 7.",
+                bit
+            )));
+        }
+        let byte = self.u8_at(byte_offset)?;
+        Ok(match order {
+            BitOrder::MsbFirst => byte & (0x80 >> bit) != 0,
+            BitOrder::LsbFirst => byte & (1 << bit) != 0,
+        })
+    }
+
+    /// Reads an IFF/RIFF-style chunk, which consists of a four byte ASCII id followed by a `u32`
+    /// length (in the provided endidness) and then that many bytes of data. The id is returned
+    /// along with a [`SliceRefBinReader`] over the chunk's data, whose initial offset is the
+    /// offset of the data's first byte. Afterwards, the [`BinReader::current_offset`] is advanced
+    /// past the data, plus however much padding is needed to align the data's length to
+    /// `pad_to` bytes (a `pad_to` of `0` or `1` means there is no padding). If the data ends
+    /// before the padding does, as happens with some writers that leave off the final chunk's pad
+    /// byte, then the offset is just advanced to the end of the data.
+    fn next_chunk(
+        &self,
+        length_endidness: Endidness,
+        pad_to: usize,
+    ) -> Result<(String, SliceRefBinReader<'_>)> {
+        let start = self.current_offset();
+        let id = self.subseq(start, 4)?;
+        if let Some(i) = id.iter().position(|byte| !byte.is_ascii()) {
+            return Err(Error::InvalidData(start + i));
+        }
+        let len = match length_endidness {
+            Endidness::Big => self.u32_be_at(start + 4)?,
+            Endidness::Little => self.u32_le_at(start + 4)?,
+            Endidness::Unknown => return Err(Error::UnknownEndidness),
+        } as usize;
+        let data_start = start + 8;
+        let reader = SliceRefBinReader::from_slice_with_offset(
+            self.subseq(data_start, len)?,
+            data_start,
+            self.endidness(),
+        )?;
+        let data_end = data_start + len;
+        let padding = if pad_to > 1 {
+            (pad_to - len % pad_to) % pad_to
+        } else {
+            0
+        };
+        self.advance_to(data_end + padding.min(self.upper_offset_limit() - data_end))?;
+        Ok((str::from_utf8(id)?.to_string(), reader))
+    }
+
+    /// Reads `u32`s using the default endidness until fewer than four bytes are left. If any
+    /// bytes are left over, then [`Error::NotEnoughData`] is returned without reading anything,
+    /// unless `allow_remainder` is `true`, in which case the leftover bytes are left unread. If
+    /// the current endidness is [`Endidness::Unknown`], then an error is returned.
+    fn read_to_end_u32(&self, allow_remainder: bool) -> Result<Vec<u32>> {
+        let remainder = self.remaining() % 4;
+        if remainder != 0 && !allow_remainder {
+            return Err(Error::NotEnoughData(4, remainder));
+        }
+        (0..self.remaining() / 4).map(|_| self.next_u32()).collect()
+    }
+
+    /// Reads a BER/DER tag-length-value header, returning the tag byte, the length and the value
+    /// itself. Both the short form of the length (a single byte below `0x80`) and the long form
+    /// (`0x80` plus the number of big endian length bytes that follow) are supported, but the
+    /// indefinite length form is not. Afterwards, the [`BinReader::current_offset`] is advanced
+    /// past the value.
+    fn next_asn1_tlv(&self) -> Result<(u8, u64, &[u8])> {
+        let start = self.current_offset();
+        let tag = self.u8_at(start)?;
+        let first = self.u8_at(start + 1)?;
+        let (len, header_len) = if first & 0x80 == 0 {
+            (first as u64, 2)
+        } else {
+            let num_bytes = (first & 0x7f) as usize;
+            if num_bytes == 0 || num_bytes == 0x7f {
+                return Err(Error::InvalidData(start + 1));
+            } else if num_bytes > 8 {
+                return Err(Error::VarintTooLong);
+            }
+            let len = self
+                .subseq(start + 2, num_bytes)?
+                .iter()
+                .fold(0, |len, byte| (len << 8) | *byte as u64);
+            (len, 2 + num_bytes)
+        };
+        let value_len =
+            usize::try_from(len).map_err(|_| Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        let value = self.subseq(start + header_len, value_len)?;
+        self.advance_to(start + header_len + value_len)?;
+        Ok((tag, len, value))
+    }
+
+    /// Parses `count` elements using `parse`, passing each one to `validate` after it's parsed.
+    /// If either function fails, then the whole read fails with the error wrapped in an
+    /// [`Error::At`] containing the index of the offending element.
+    fn read_validated_array<T, F, V>(&self, count: usize, parse: F, validate: V) -> Result<Vec<T>>
+    where
+        F: Fn(&Self) -> Result<T>,
+        V: Fn(&T) -> Result<()>,
+    {
+        let mut values = Vec::with_capacity(count.min(self.remaining()));
+        for i in 0..count {
+            let value = parse(self)
+                .and_then(|value| validate(&value).map(|_| value))
+                .map_err(|e| Error::At(i, Box::new(e)))?;
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Reads a 16 byte Microsoft style GUID and formats it in its canonical
+    /// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` form. The first three fields are always read as
+    /// little endian (regardless of the reader's endidness), while the last eight bytes are
+    /// formatted as is.
+    fn next_guid_string(&self) -> Result<String> {
+        let bytes = self.subseq(self.current_offset(), 16)?;
+        let guid = format!(
+            "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+            u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            u16::from_le_bytes([bytes[4], bytes[5]]),
+            u16::from_le_bytes([bytes[6], bytes[7]]),
+            bytes[8],
+            bytes[9],
+            bytes[10],
+            bytes[11],
+            bytes[12],
+            bytes[13],
+            bytes[14],
+            bytes[15],
+        );
+        self.advance_by(16)?;
+        Ok(guid)
+    }
+
+    /// Reads a fixed size field of `code_units` UTF-16 code units using the default endidness,
+    /// such as the `WCHAR name[32]` fields found in many Windows structs. Decoding stops at the
+    /// first NUL code unit, but the [`BinReader::current_offset`] is always advanced past the
+    /// whole field. If the current endidness is [`Endidness::Unknown`], then an error is
+    /// returned.
+    fn read_utf16_fixed_field(&self, code_units: usize) -> Result<String> {
+        let num_bytes = code_units
+            .checked_mul(2)
+            .ok_or(Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        let data = self.subseq(self.current_offset(), num_bytes)?;
+        let units: Vec<u16> = util::utf16_units(data, self.endidness())?
+            .take_while(|unit| *unit != 0)
+            .collect();
+        let string = String::from_utf16(&units)?;
+        self.advance_by(num_bytes as isize)?;
+        Ok(string)
+    }
+
+    /// Reads `pair_count` run-length encoded `(count, value)` pairs of `u8`s, expanding each one
+    /// into `count` copies of `value`. All of the pairs are validated before anything is
+    /// allocated, and if they would expand to more than `max_len` bytes, then an [`Error::Other`]
+    /// is returned without reading anything.
+    fn read_rle_u8(&self, pair_count: usize, max_len: usize) -> Result<Vec<u8>> {
+        let num_bytes = pair_count
+            .checked_mul(2)
+            .ok_or(Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        let pairs = self.subseq(self.current_offset(), num_bytes)?;
+        let total = pairs.chunks_exact(2).map(|pair| pair[0] as usize).sum();
+        if total > max_len {
+            return Err(Error::Other(format!(
+                "An expanded length of {} is larger than the maximum of {}.",
+                total, max_len
+            )));
+        }
+        let mut values = Vec::with_capacity(total);
+        for pair in pairs.chunks_exact(2) {
+            values.extend(std::iter::repeat_n(pair[1], pair[0] as usize));
+        }
+        self.advance_by(num_bytes as isize)?;
+        Ok(values)
+    }
+
+    /// Reads a `u32` at the provided offset as big endian if `big_if` is `true`, and as little
+    /// endian otherwise, regardless of the reader's endidness. This is useful for formats where
+    /// a header flag determines the byte order of later fields.
+    #[inline]
+    fn u32_conditional(&self, offset: usize, big_if: bool) -> Result<u32> {
+        if big_if {
+            self.u32_be_at(offset)
+        } else {
+            self.u32_le_at(offset)
+        }
+    }
+
+    /// Reads a `u32` using the default endidness at the [`BinReader::current_offset`], then
+    /// advances by `stride` bytes instead of `4`, so that the next read lands on the next record
+    /// of a padded array. The whole stride has to be available, and a `stride` of less than `4`
+    /// results in an [`Error::Other`].
+    fn next_u32_strided(&self, stride: usize) -> Result<u32> {
+        if stride < 4 {
+            return Err(Error::Other(format!(
+                "A stride of {} bytes is too small to hold a u32.",
+                stride
+            )));
+        }
+        self.validate_offset(self.current_offset(), stride)?;
+        let value = self.u32_at(self.current_offset())?;
+        self.advance_by(stride as isize)?;
+        Ok(value)
+    }
+
+    /// Walks all of the reader's data as IFF/RIFF-style chunks (see [`BinReader::next_chunk`]),
+    /// returning the id of each chunk along with the range of offsets its data occupies. The
+    /// [`BinReader::current_offset`] is restored afterwards, even if an error occurs.
+    fn index_chunks(
+        &self,
+        length_endidness: Endidness,
+        pad_to: usize,
+    ) -> Result<Vec<(String, Range<usize>)>> {
+        let original = self.current_offset();
+        util::rewind(self, self.initial_offset())?;
+        let mut chunks = Vec::new();
+        let result = loop {
+            if self.is_empty() {
+                break Ok(chunks);
+            }
+            match self.next_chunk(length_endidness, pad_to) {
+                Ok((id, data)) => {
+                    chunks.push((id, data.initial_offset()..data.upper_offset_limit()));
+                }
+                Err(e) => break Err(e),
+            }
+        };
+        util::rewind(self, original)?;
+        result
+    }
+
+    /// Reads an integer written by .NET's `BinaryWriter.Write7BitEncodedInt`, which is a little
+    /// endian base 128 encoding that's at most five bytes long. If the value doesn't fit into a
+    /// `u32`, then [`Error::VarintTooLong`] is returned. The [`BinReader::current_offset`] is only
+    /// advanced if the value is read successfully.
+    fn next_7bit_encoded_int(&self) -> Result<u32> {
+        let start = self.current_offset();
+        let mut value = 0;
+        for i in 0..5 {
+            let byte = self.u8_at(start + i)?;
+            if i == 4 && byte > 0x0f {
+                return Err(Error::VarintTooLong);
+            }
+            value |= ((byte & 0x7f) as u32) << (i * 7);
+            if byte & 0x80 == 0 {
+                self.advance_by(i as isize + 1)?;
+                return Ok(value);
+            }
+        }
+        Err(Error::VarintTooLong)
+    }
+
+    /// Returns the data between the provided starting and ending offsets as a [`Cow`]. Readers
+    /// that can lend out their data return a [`Cow::Borrowed`] (which all of this crate's readers
+    /// do), while readers that can't may return a [`Cow::Owned`] copy instead.
+    #[inline]
+    fn cow_range(&self, start: usize, end: usize) -> Result<Cow<'_, [u8]>> {
+        self.range(start, end).map(Cow::Borrowed)
+    }
+
+    /// Reads a `u16` discriminant using the default endidness and converts it using `map`. Since
+    /// `map` always has to return a value, unrecognized discriminants never cause an error, which
+    /// makes this useful for forward-compatible parsing (e.g. by mapping them to an
+    /// `Unknown(u16)` variant).
+    #[inline]
+    fn next_u16_enum_or<T, F: Fn(u16) -> T>(&self, map: F) -> Result<T> {
+        self.next_u16().map(map)
+    }
+
+    /// Reads a `total_len` byte record that consists of a fixed head followed by an opaque tail.
+    /// The head is parsed via `parse_head`, and whatever's left of the record afterwards is
+    /// returned as the tail. The [`BinReader::current_offset`] is advanced past the whole record
+    /// on success and left where it was on failure (including if `parse_head` reads past the end
+    /// of the record).
+    fn read_with_tail<T, F: Fn(&Self) -> Result<T>>(
+        &self,
+        total_len: usize,
+        parse_head: F,
+    ) -> Result<(T, &[u8])> {
+        let start = self.current_offset();
+        self.validate_offset(start, total_len)?;
+        let end = start + total_len;
+        let head = match parse_head(self) {
+            Ok(_) if self.current_offset() > end => Err(Error::Other(format!(
+                "Parsing the head read {} bytes, which is more than the record's {} bytes.",
+                self.current_offset() - start,
+                total_len
+            ))),
+            head => head,
+        };
+        match head {
+            Ok(head) => {
+                let tail = self.range(self.current_offset(), end)?;
+                self.advance_to(end)?;
+                Ok((head, tail))
+            }
+            Err(e) => {
+                util::rewind(self, start)?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Reads the `u32` (using the default endidness) found `field_offset` bytes into each of
+    /// `count` records that are `record_stride` bytes apart, starting at the
+    /// [`BinReader::current_offset`]. Afterwards, the [`BinReader::current_offset`] is advanced
+    /// past all of the records. The field has to fit inside of a single record.
+    fn read_field_column_u32(
+        &self,
+        record_stride: usize,
+        field_offset: usize,
+        count: usize,
+    ) -> Result<Vec<u32>> {
+        if field_offset.saturating_add(4) > record_stride {
+            return Err(Error::Other(format!(
+                "A u32 at offset {} does not fit in a record of {} bytes.",
+                field_offset, record_stride
+            )));
+        }
+        let start = self.current_offset();
+        let num_bytes = record_stride
+            .checked_mul(count)
+            .ok_or(Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        self.validate_offset(start, num_bytes)?;
+        let values = (0..count)
+            .map(|i| self.u32_at(start + i * record_stride + field_offset))
+            .collect::<Result<_>>()?;
+        self.advance_by(num_bytes as isize)?;
+        Ok(values)
+    }
+
+    /// Reads the record at `index` in an array of records that each consist of `fields` `u32`s
+    /// (using the default endidness), where the array starts at the absolute offset `base`. This
+    /// does not alter the [`BinReader::current_offset`].
+    fn record_at_u32_array(&self, base: usize, index: usize, fields: usize) -> Result<Vec<u32>> {
+        let record_len = fields
+            .checked_mul(4)
+            .ok_or(Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        let offset = index
+            .checked_mul(record_len)
+            .and_then(|offset| offset.checked_add(base))
+            .ok_or(Error::OffsetTooLarge(usize::MAX))?;
+        self.validate_offset(offset, record_len)?;
+        (0..fields).map(|i| self.u32_at(offset + i * 4)).collect()
+    }
+
+    /// Checks that the reader holds exactly `declared` bytes (e.g. a total size that was read
+    /// from a header). If it doesn't, then an error describing whether the data is truncated or
+    /// has trailing data is returned.
+    fn verify_declared_size(&self, declared: usize) -> Result<()> {
+        let have = self.upper_offset_limit() - self.initial_offset();
+        if have < declared {
+            Err(Error::Other(format!(
+                "The data is truncated: declared {} bytes but have {}.",
+                declared, have
+            )))
+        } else if have > declared {
+            Err(Error::Other(format!(
+                "The data has trailing bytes: declared {} bytes but have {}.",
+                declared, have
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Reads a `(major, minor, patch)` version triple made up of `u16`s using the default
+    /// endidness. If the current endidness is [`Endidness::Unknown`], then an error is returned.
+    fn next_version_triple_u16(&self) -> Result<(u16, u16, u16)> {
+        self.validate_offset(self.current_offset(), 6)?;
+        Ok((self.next_u16()?, self.next_u16()?, self.next_u16()?))
+    }
+
+    /// Reads a `(major, minor, patch)` version triple made up of `u8`s.
+    fn next_version_triple_u8(&self) -> Result<(u8, u8, u8)> {
+        self.validate_offset(self.current_offset(), 3)?;
+        Ok((self.next_u8()?, self.next_u8()?, self.next_u8()?))
+    }
+
+    /// Reads an opcode byte and then calls `handler` with it, at which point the
+    /// [`BinReader::current_offset`] is just past the opcode so that the handler can read the
+    /// opcode's operands.
+    #[inline]
+    fn dispatch_u8<T, F: Fn(&Self, u8) -> Result<T>>(&self, handler: F) -> Result<T> {
+        let opcode = self.next_u8()?;
+        handler(self, opcode)
+    }
+
+    /// Returns a compact, one line summary of where the reader is, meant to be used as a key when
+    /// correlating log messages. It contains the current offset, the upper offset limit and the
+    /// endidness (`be`, `le` or `unknown`), e.g. `off=0x1A/0x40 le`.
+    fn locator(&self) -> String {
+        let endidness = match self.endidness() {
+            Endidness::Big => "be",
+            Endidness::Little => "le",
+            Endidness::Unknown => "unknown",
+        };
+        format!(
+            "off=0x{:X}/0x{:X} {}",
+            self.current_offset(),
+            self.upper_offset_limit(),
+            endidness
+        )
+    }
+
+    #[cfg(feature = "chrono")]
+    /// Reads a `u32` count of days using the default endidness and adds it to `epoch`. If the
+    /// resulting date is out of range, then [`Error::InvalidData`] is returned. If the current
+    /// endidness is [`Endidness::Unknown`], then an error is returned.
+    fn next_days_since_epoch_u32(&self, epoch: chrono::NaiveDate) -> Result<chrono::NaiveDate> {
+        let offset = self.current_offset();
+        let date = epoch
+            .checked_add_days(chrono::Days::new(self.u32_at(offset)? as u64))
+            .ok_or(Error::InvalidData(offset))?;
+        self.advance_by(4)?;
+        Ok(date)
+    }
+
+    #[cfg(feature = "chrono")]
+    /// Functions the same as [`BinReader::next_days_since_epoch_u32`], except that the count of
+    /// days is a `u16`.
+    fn next_days_since_epoch_u16(&self, epoch: chrono::NaiveDate) -> Result<chrono::NaiveDate> {
+        let offset = self.current_offset();
+        let date = epoch
+            .checked_add_days(chrono::Days::new(self.u16_at(offset)? as u64))
+            .ok_or(Error::InvalidData(offset))?;
+        self.advance_by(2)?;
+        Ok(date)
+    }
+
+    /// Reads `count` raw integers that are each `raw_width` bytes wide (between `1` and `8`)
+    /// using the default endidness, and returns `raw * scale + bias` for each one. This is the
+    /// usual way to calibrate sensor and scientific data. The raw integers are treated as two's
+    /// complement if `signed` is `true`.
+    fn read_scaled_f64(
+        &self,
+        count: usize,
+        scale: f64,
+        bias: f64,
+        raw_width: usize,
+        signed: bool,
+    ) -> Result<Vec<f64>> {
+        if !(1..=8).contains(&raw_width) {
+            return Err(Error::Other(format!(
+                "A width of {} bytes is invalid; it must be between 1 and 8.",
+                raw_width
+            )));
+        }
+        let start = self.current_offset();
+        let num_bytes = raw_width
+            .checked_mul(count)
+            .ok_or(Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        self.validate_offset(start, num_bytes)?;
+        let mask = u64::MAX >> (64 - raw_width * 8);
+        let values = (0..count)
+            .map(|i| {
+                let raw = self.int_at_with(start + i * raw_width, raw_width, self.endidness())?;
+                let raw = if signed {
+                    raw as f64
+                } else {
+                    (raw as u64 & mask) as f64
+                };
+                Ok(raw * scale + bias)
+            })
+            .collect::<Result<_>>()?;
+        self.advance_by(num_bytes as isize)?;
+        Ok(values)
+    }
+
+    /// Reads the next signed PCM audio sample that's `width` bytes wide (`1`, `2`, `3` or `4`)
+    /// using the default endidness, and normalizes it to a value between `-1.0` and `1.0` by
+    /// dividing it by `2^(bits - 1)`. This means that the minimum value becomes exactly `-1.0`,
+    /// while the maximum value comes out just short of `1.0`.
+    fn next_sample_normalized(&self, width: usize) -> Result<f32> {
+        if !(1..=4).contains(&width) {
+            return Err(Error::Other(format!(
+                "A sample width of {} bytes is invalid; it must be between 1 and 4.",
+                width
+            )));
+        }
+        let sample = self.int_at_with(self.current_offset(), width, self.endidness())?;
+        self.advance_by(width as isize)?;
+        Ok((sample as f64 / (1u64 << (width * 8 - 1)) as f64) as f32)
+    }
+
+    /// Reads an interleaved stereo frame of two `i16` samples (left, then right) using the
+    /// default endidness, then advances past it.
+    fn next_stereo_sample_i16(&self) -> Result<(i16, i16)> {
+        let offset = self.current_offset();
+        self.validate_offset(offset, 4)?;
+        let frame = (self.i16_at(offset)?, self.i16_at(offset + 2)?);
+        self.advance_by(4)?;
+        Ok(frame)
+    }
+
+    /// Reads `frames` interleaved stereo frames (see [`BinReader::next_stereo_sample_i16`]). The
+    /// [`BinReader::current_offset`] is only advanced if every frame could be read.
+    fn read_stereo_i16_vec(&self, frames: usize) -> Result<Vec<(i16, i16)>> {
+        let start = self.current_offset();
+        let num_bytes = frames
+            .checked_mul(4)
+            .ok_or(Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        self.validate_offset(start, num_bytes)?;
+        let values = (0..frames)
+            .map(|i| {
+                let offset = start + i * 4;
+                Ok((self.i16_at(offset)?, self.i16_at(offset + 2)?))
+            })
+            .collect::<Result<_>>()?;
+        self.advance_by(num_bytes as isize)?;
+        Ok(values)
+    }
+
+    /// Reads a 16-bit RGB565 color using the default endidness, where red is stored in the top 5
+    /// bits, green in the middle 6 and blue in the bottom 5, and then advances past it. Each
+    /// channel is expanded to 8 bits by replicating its high bits, so that the maximum value of
+    /// each channel becomes `255`.
+    fn next_rgb565(&self) -> Result<(u8, u8, u8)> {
+        let value = self.u16_at(self.current_offset())?;
+        self.advance_by(2)?;
+        Ok((
+            util::expand_channel(value >> 11, 5),
+            util::expand_channel(value >> 5, 6),
+            util::expand_channel(value, 5),
+        ))
+    }
+
+    /// Reads a 16-bit RGB555 color using the default endidness, where the top bit is unused and
+    /// red, green and blue each take up 5 bits, and then advances past it. The channels are
+    /// expanded the same way as [`BinReader::next_rgb565`].
+    fn next_rgb555(&self) -> Result<(u8, u8, u8)> {
+        let value = self.u16_at(self.current_offset())?;
+        self.advance_by(2)?;
+        Ok((
+            util::expand_channel(value >> 10, 5),
+            util::expand_channel(value >> 5, 5),
+            util::expand_channel(value, 5),
+        ))
+    }
+
+    /// Reads a 16-bit RGBA4444 color using the default endidness, where red, green, blue and
+    /// alpha each take up 4 bits (from the top bits down), and then advances past it. The
+    /// channels are expanded the same way as [`BinReader::next_rgb565`].
+    fn next_rgba4444(&self) -> Result<(u8, u8, u8, u8)> {
+        let value = self.u16_at(self.current_offset())?;
+        self.advance_by(2)?;
+        Ok((
+            util::expand_channel(value >> 12, 4),
+            util::expand_channel(value >> 8, 4),
+            util::expand_channel(value >> 4, 4),
+            util::expand_channel(value, 4),
+        ))
+    }
+
+    /// Returns `true` if the next bytes match the provided pattern, where `None` matches any byte
+    /// and `Some(byte)` only matches that byte. This is useful for signatures that contain
+    /// variable bytes. This does not alter the [`BinReader::current_offset`].
+    fn remaining_matches(&self, pattern: &[Option<u8>]) -> Result<bool> {
+        Ok(self
+            .subseq(self.current_offset(), pattern.len())?
+            .iter()
+            .zip(pattern)
+            .all(|(byte, expected)| expected.is_none_or(|expected| *byte == expected)))
+    }
+
+    /// Reads the next `len` bytes and checks that every one of them is `byte`, as is needed to
+    /// validate reserved or must-be-zero regions. If any byte differs, an
+    /// [`Error::InvalidData`] is returned with the offset of the first one that does and the
+    /// [`BinReader::current_offset`] is left alone; otherwise it is advanced past the region.
+    fn expect_all(&self, len: usize, byte: u8) -> Result<()> {
+        let offset = self.current_offset();
+        let data = self.subseq(offset, len)?;
+        if let Some(pos) = data.iter().position(|b| *b != byte) {
+            return Err(Error::InvalidData(offset + pos));
+        }
+        self.advance_by(len as isize)?;
+        Ok(())
+    }
+
+    /// Reads a signature made up of a fixed part followed by `version_len` version bytes, such as
+    /// `"GIF89a"`. If the next bytes don't match `fixed`, an [`Error::BadMagic`] is returned.
+    /// Otherwise, the version bytes are returned and the [`BinReader::current_offset`] is advanced
+    /// past both parts.
+    fn read_signature_with_version(&self, fixed: &[u8], version_len: usize) -> Result<Vec<u8>> {
+        let offset = self.current_offset();
+        let signature = self.subseq(offset, fixed.len() + version_len)?;
+        if &signature[..fixed.len()] != fixed {
+            return Err(Error::BadMagic(offset));
+        }
+        let version = signature[fixed.len()..].to_vec();
+        self.advance_by(signature.len() as isize)?;
+        Ok(version)
+    }
+
+    /// Follows a pointer stored in a footer, as is done by ZIP-like formats to locate their
+    /// central directory. The `u32` located `footer_offset_from_end` bytes before the
+    /// [`BinReader::upper_offset_limit`] is read using the default endidness and treated as an
+    /// absolute offset. A [`SliceRefBinReader`] over the data from that offset up to the footer
+    /// is then returned, with its initial offset being the offset that was pointed to. This does
+    /// not alter the [`BinReader::current_offset`].
+    fn follow_footer_u32(&self, footer_offset_from_end: usize) -> Result<SliceRefBinReader<'_>> {
+        let footer = self
+            .upper_offset_limit()
+            .checked_sub(footer_offset_from_end)
+            .ok_or(Error::OffsetTooSmall(0))?;
+        let target = self.u32_at(footer)? as usize;
+        self.slice_reader_with_offset(target, target, footer)
+    }
+
+    /// Reads a variable-length integer using a configurable scheme. Each byte holds
+    /// `bits_per_byte` bits of the value (between `1` and `7`) along with a continuation bit,
+    /// which is the most significant bit if `continuation_high` is `true` and the least
+    /// significant bit otherwise. The value's bits sit right next to the continuation bit, so any
+    /// unused bits are at the other end of the byte and are ignored. The groups are ordered least
+    /// significant first if `little_endian_groups` is `true` and most significant first otherwise.
+    /// For example, LEB128 is `(7, true, true)` while MIDI's variable-length quantities are
+    /// `(7, false, true)`.
+    ///
+    /// If the value is too large to fit into a `u64`, then [`Error::VarintTooLong`] is returned.
+    /// The [`BinReader::current_offset`] is only advanced if the value is read successfully.
+    fn next_varint_custom(
+        &self,
+        bits_per_byte: u8,
+        little_endian_groups: bool,
+        continuation_high: bool,
+    ) -> Result<u64> {
+        if !(1..=7).contains(&bits_per_byte) {
+            return Err(Error::Other(format!(
+                "{} bits per byte is invalid; it must be between 1 and 7.",
+                bits_per_byte
+            )));
+        }
+        let bits = bits_per_byte as u32;
+        let (continuation, payload_shift) = if continuation_high {
+            (0x80, 7 - bits_per_byte)
+        } else {
+            (0x01, 1)
+        };
+        let start = self.current_offset();
+        let mut value: u64 = 0;
+        let mut shift = 0;
+        for i in 0.. {
+            let byte = self.u8_at(start + i)?;
+            let group = ((byte >> payload_shift) as u64) & ((1 << bits) - 1);
+            if little_endian_groups {
+                if shift >= 64 || (group << shift) >> shift != group {
+                    return Err(Error::VarintTooLong);
+                }
+                value |= group << shift;
+                shift += bits;
+            } else {
+                if value.leading_zeros() < bits {
+                    return Err(Error::VarintTooLong);
+                }
+                value = (value << bits) | group;
+            }
+            if byte & continuation == 0 {
+                self.advance_by(i as isize + 1)?;
+                break;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Reads a SQLite style variable-length integer, which is big endian and between one and
+    /// nine bytes long. The first eight bytes each hold seven bits of the value (with the most
+    /// significant bit signaling that more bytes follow), while the ninth byte, if present,
+    /// holds a full eight bits. The [`BinReader::current_offset`] is only advanced if the value
+    /// is read successfully.
+    fn next_sqlite_varint(&self) -> Result<u64> {
+        let start = self.current_offset();
+        let mut value: u64 = 0;
+        for i in 0..8 {
+            let byte = self.u8_at(start + i)?;
+            value = (value << 7) | (byte & 0x7f) as u64;
+            if byte & 0x80 == 0 {
+                self.advance_by(i as isize + 1)?;
+                return Ok(value);
+            }
+        }
+        value = (value << 8) | self.u8_at(start + 8)? as u64;
+        self.advance_by(9)?;
+        Ok(value)
+    }
+
+    /// Gets the NUL-terminated string starting at the provided offset, not including the
+    /// terminator. If there's no terminator before the end of the data, then
+    /// [`Error::NoMoreData`] is returned. This does not alter the [`BinReader::current_offset`].
+    fn cstr_at(&self, offset: usize) -> Result<&[u8]> {
+        let data = self.range(offset, self.upper_offset_limit())?;
+        data.iter()
+            .position(|byte| *byte == 0)
+            .map(|len| &data[..len])
+            .ok_or(Error::NoMoreData)
+    }
+
+    /// Reads a NUL-terminated string (see [`BinReader::cstr_at`]) starting at the
+    /// [`BinReader::current_offset`], then advances past the terminator.
+    fn next_cstr(&self) -> Result<&[u8]> {
+        let cstr = self.cstr_at(self.current_offset())?;
+        self.advance_by(cstr.len() as isize + 1)?;
+        Ok(cstr)
+    }
+
+    /// Functions the same as [`BinReader::next_cstr`], except the string is validated as UTF-8.
+    /// If it isn't valid, then an error is returned and the [`BinReader::current_offset`] isn't
+    /// advanced.
+    fn next_cstr_utf8(&self) -> Result<&str> {
+        let cstr = str::from_utf8(self.cstr_at(self.current_offset())?)?;
+        self.advance_by(cstr.len() as isize + 1)?;
+        Ok(cstr)
+    }
+
+    /// Reads exactly `char_count` UTF-8 encoded characters (i.e. code points, not bytes) starting
+    /// at the [`BinReader::current_offset`], and then advances past the bytes that made them up.
+    /// If an invalid sequence is found, then an [`Error::InvalidData`] with the sequence's offset
+    /// is returned, and if the data runs out before enough characters are read, then the same
+    /// error that [`BinReader::subseq`] would return is. In either case, the
+    /// [`BinReader::current_offset`] isn't advanced.
+    fn next_utf8_chars(&self, char_count: usize) -> Result<String> {
+        let mut string = String::with_capacity(char_count.min(self.remaining()));
+        let mut offset = self.current_offset();
+        for _ in 0..char_count {
+            let seq_len = match self.u8_at(offset)? {
+                0x00..=0x7f => 1,
+                0xc2..=0xdf => 2,
+                0xe0..=0xef => 3,
+                0xf0..=0xf4 => 4,
+                _ => return Err(Error::InvalidData(offset)),
+            };
+            let seq = str::from_utf8(self.subseq(offset, seq_len)?)
+                .map_err(|_| Error::InvalidData(offset))?;
+            string.push_str(seq);
+            offset += seq_len;
+        }
+        self.advance_to(offset)?;
+        Ok(string)
+    }
+
+    /// Reads a list of NUL-terminated strings (see [`BinReader::cstr_at`]) that ends with an empty
+    /// string (i.e. a double NUL), as is used for argv and environment blocks. The
+    /// [`BinReader::current_offset`] is advanced past the final terminator, but only if the whole
+    /// list could be read.
+    fn read_cstr_list(&self) -> Result<Vec<&[u8]>> {
+        let mut offset = self.current_offset();
+        let mut strings = Vec::new();
+        loop {
+            let cstr = self.cstr_at(offset)?;
+            offset += cstr.len() + 1;
+            if cstr.is_empty() {
+                break;
+            }
+            strings.push(cstr);
+        }
+        self.advance_to(offset)?;
+        Ok(strings)
+    }
+
+    /// Reads all of the remaining data as a Mapbox Vector Tile geometry. Each command is an
+    /// unsigned LEB128 (see [`BinReader::next_uleb128`]) whose lowest three bits are the command
+    /// id and whose remaining bits are how many times it repeats. `MoveTo` (`1`) and `LineTo`
+    /// (`2`) are followed by a pair of zigzag encoded (see [`util::zigzag_decode`]) deltas per
+    /// repetition, while `ClosePath` (`7`) has none. The deltas are applied starting at `(0, 0)`,
+    /// so the returned coordinates are absolute. An unknown command id, or a `ClosePath` that
+    /// doesn't have a count of exactly `1`, results in an [`Error::InvalidData`]. If there isn't
+    /// enough data left for all of a command's deltas, then [`Error::NotEnoughData`] is returned
+    /// before any of them are read.
+    fn read_geometry_commands(&self) -> Result<Vec<GeometryCommand>> {
+        let mut commands = Vec::new();
+        let (mut x, mut y) = (0i32, 0i32);
+        while !self.is_empty() {
+            let offset = self.current_offset();
+            let command = self.next_uleb128()?;
+            let count = command >> 3;
+            match command & 0x7 {
+                id @ (1 | 2) => {
+                    // Every delta takes up at least one byte.
+                    let num_deltas = usize::try_from(count)
+                        .ok()
+                        .and_then(|count| count.checked_mul(2))
+                        .unwrap_or(usize::MAX);
+                    if num_deltas > self.remaining() {
+                        return Err(Error::NotEnoughData(num_deltas, self.remaining()));
+                    }
+                    for _ in 0..count {
+                        for coord in [&mut x, &mut y] {
+                            let offset = self.current_offset();
+                            let delta = i32::try_from(util::zigzag_decode(self.next_uleb128()?))
+                                .map_err(|_| Error::VarintTooLong)?;
+                            *coord = coord.checked_add(delta).ok_or(Error::InvalidData(offset))?;
+                        }
+                        commands.push(if id == 1 {
+                            GeometryCommand::MoveTo(x, y)
+                        } else {
+                            GeometryCommand::LineTo(x, y)
+                        });
+                    }
+                }
+                7 if count == 1 => commands.push(GeometryCommand::ClosePath),
+                _ => return Err(Error::InvalidData(offset)),
+            }
+        }
+        Ok(commands)
+    }
+
+    /// Reads exactly `len` bytes, returning them with any trailing NUL padding removed.
+    fn next_fixed_str(&self, len: usize) -> Result<&[u8]> {
+        let data = self.subseq(self.current_offset(), len)?;
+        let trimmed_len = data
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |i| i + 1);
+        self.advance_by(len as isize)?;
+        Ok(&data[..trimmed_len])
+    }
+
+    /// Reads a `u8` length followed by that many bytes, which are returned.
+    fn next_pascal_str_u8(&self) -> Result<&[u8]> {
+        let len = self.u8_at(self.current_offset())? as usize;
+        let data = self.subseq(self.current_offset() + 1, len)?;
+        self.advance_by(len as isize + 1)?;
+        Ok(data)
+    }
+
+    /// Reads a `u16` length using the default endidness followed by that many bytes, which are
+    /// returned. If the current endidness is [`Endidness::Unknown`], then an error is returned.
+    fn next_pascal_str_u16(&self) -> Result<&[u8]> {
+        let len = self.u16_at(self.current_offset())? as usize;
+        let data = self.subseq(self.current_offset() + 2, len)?;
+        self.advance_by(len as isize + 2)?;
+        Ok(data)
+    }
+
+    /// Reads a `u32` length using the default endidness followed by that many bytes, which are
+    /// returned. If the current endidness is [`Endidness::Unknown`], then an error is returned.
+    fn next_pascal_str_u32(&self) -> Result<&[u8]> {
+        let len = self.u32_at(self.current_offset())? as usize;
+        let data = self.subseq(self.current_offset() + 4, len)?;
+        self.advance_by(len as isize + 4)?;
+        Ok(data)
+    }
+
+    /// Reads `record_count` records that each consist of `field_count` consecutive `u32`s (using
+    /// the default endidness), and returns them transposed so that there's one [`Vec`] per
+    /// field. If the current endidness is [`Endidness::Unknown`], then an error is returned. Even
+    /// if `record_count` is `0`, a `field_count` too large for a single record to fit into the
+    /// remaining data results in an [`Error::NotEnoughData`].
+    fn read_soa_u32(&self, field_count: usize, record_count: usize) -> Result<Vec<Vec<u32>>> {
+        if field_count == 0 {
+            return Ok(Vec::new());
+        }
+        if field_count > self.remaining() / 4 {
+            return Err(Error::NotEnoughData(
+                field_count.saturating_mul(4),
+                self.remaining(),
+            ));
+        }
+        let num_bytes = field_count
+            .checked_mul(record_count)
+            .and_then(|count| count.checked_mul(4))
+            .ok_or(Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        self.validate_offset(self.current_offset(), num_bytes)?;
+        if record_count > 0 && matches!(self.endidness(), Endidness::Unknown) {
+            return Err(Error::UnknownEndidness);
+        }
+        // Cloning a Vec doesn't keep its capacity, so each field has to be built separately.
+        let mut fields: Vec<Vec<u32>> = (0..field_count)
+            .map(|_| Vec::with_capacity(record_count))
+            .collect();
+        for _ in 0..record_count {
+            for field in fields.iter_mut() {
+                field.push(self.next_u32()?);
+            }
+        }
+        Ok(fields)
+    }
+
+    /// Reads UTF-16 code units using the default endidness until a NUL code unit is found, then
+    /// decodes them and advances past the terminator. If there's no terminator before the end of
+    /// the data, then [`Error::NoMoreData`] is returned. Invalid UTF-16 (such as an unpaired
+    /// surrogate) results in an [`Error::Other`]. In either case, the
+    /// [`BinReader::current_offset`] isn't advanced.
+    fn next_utf16_cstr(&self) -> Result<String> {
+        let units: Vec<u16> = util::utf16_units(self.get_remaining()?, self.endidness())?
+            .take_while(|unit| *unit != 0)
+            .collect();
+        if (units.len() + 1) * 2 > self.remaining() {
+            return Err(Error::NoMoreData);
+        }
+        let string = String::from_utf16(&units)?;
+        self.advance_by((units.len() as isize + 1) * 2)?;
+        Ok(string)
+    }
+
+    /// Reads and decodes exactly `units` UTF-16 code units using the default endidness. Unlike
+    /// [`BinReader::read_utf16_fixed_field`], NUL code units are kept as is. Invalid UTF-16 (such
+    /// as an unpaired surrogate) results in an [`Error::Other`] and the
+    /// [`BinReader::current_offset`] not being advanced.
+    fn next_utf16_fixed(&self, units: usize) -> Result<String> {
+        let num_bytes = units
+            .checked_mul(2)
+            .ok_or(Error::NotEnoughData(usize::MAX, self.remaining()))?;
+        let data = self.subseq(self.current_offset(), num_bytes)?;
+        let units: Vec<u16> = util::utf16_units(data, self.endidness())?.collect();
+        let string = String::from_utf16(&units)?;
+        self.advance_by(num_bytes as isize)?;
+        Ok(string)
+    }
+
+    #[cfg(feature = "msgpack")]
+    /// Decodes a single MessagePack value starting at the [`BinReader::current_offset`], then
+    /// advances past it. The cursor is only advanced if decoding succeeds.
+    fn next_msgpack(&self) -> Result<MsgpackValue> {
+        let (value, len) = msgpack::decode(self.get_remaining()?)?;
+        self.advance_by(len as isize)?;
+        Ok(value)
+    }
+
+    #[cfg(feature = "bytemuck")]
+    /// Reads a `T` directly from the bytes at the provided offset without altering the
+    /// [`BinReader::current_offset`]. If there aren't enough bytes for a whole `T`, then an
+    /// [`Error::NotEnoughData`] is returned.
+    ///
+    /// **NOTE**: This copies the bytes as is, so `T` is read using the _native_ endidness and
+    /// the native layout of `T` (including its packing). This means that it's only correct for
+    /// data written on the same architecture with the same layout; the reader's endidness is
+    /// ignored.
+    fn pod_at<T: bytemuck::Pod>(&self, offset: usize) -> Result<T> {
+        Ok(bytemuck::pod_read_unaligned(
+            self.subseq(offset, std::mem::size_of::<T>())?,
+        ))
+    }
+
+    #[cfg(feature = "bytemuck")]
+    /// Reads a `T` (see [`BinReader::pod_at`]) at the [`BinReader::current_offset`] and then
+    /// advances past it.
+    ///
+    /// **NOTE**: Just like [`BinReader::pod_at`], this uses the native endidness and layout, so
+    /// it's only correct for data written on the same architecture.
+    fn read_pod<T: bytemuck::Pod>(&self) -> Result<T> {
+        let value = self.pod_at(self.current_offset())?;
+        self.advance_by(std::mem::size_of::<T>() as isize)?;
+        Ok(value)
+    }
 }
 
 /// An implementor of [`OwnableBinReader`] owns the data contained within it. This means that they
@@ -573,4 +2494,35 @@ pub trait OwnableBinReader<'r>: BinReader<'r> {
     fn from_bytes(bytes: Bytes, endidness: Endidness) -> Result<Self> {
         Self::from_bytes_with_offset(bytes, 0, endidness)
     }
+
+    /// Creates a reader from a [`Vec`]. The [`Vec`] is converted into a [`Bytes`] instance, so
+    /// its data isn't copied.
+    fn from_vec_with_offset(
+        data: Vec<u8>,
+        initial_offset: usize,
+        endidness: Endidness,
+    ) -> Result<Self> {
+        Self::from_bytes_with_offset(Bytes::from(data), initial_offset, endidness)
+    }
+
+    /// Creates a reader from a [`Vec`] with an initial offset of `0`.
+    fn from_vec(data: Vec<u8>, endidness: Endidness) -> Result<Self> {
+        Self::from_vec_with_offset(data, 0, endidness)
+    }
+
+    /// Creates a reader from everything that can be read from the provided [`io::Read`].
+    fn from_reader_with_offset<R: io::Read>(
+        reader: R,
+        initial_offset: usize,
+        endidness: Endidness,
+    ) -> Result<Self> {
+        let bytes = util::bytes_from_bufread(io::BufReader::new(reader), None)?;
+        Self::from_bytes_with_offset(bytes, initial_offset, endidness)
+    }
+
+    /// Creates a reader from everything that can be read from the provided [`io::Read`], with an
+    /// initial offset of `0`.
+    fn from_reader<R: io::Read>(reader: R, endidness: Endidness) -> Result<Self> {
+        Self::from_reader_with_offset(reader, 0, endidness)
+    }
 }
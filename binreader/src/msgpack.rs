@@ -0,0 +1,67 @@
+use crate::{Error, Result};
+use rmpv::Value;
+use std::convert::TryFrom;
+
+/// A single decoded MessagePack value, as returned by
+/// [`BinReader::next_msgpack`](crate::BinReader::next_msgpack).
+#[derive(Debug, Clone, PartialEq)]
+pub enum MsgpackValue {
+    Nil,
+    Boolean(bool),
+    /// A non-negative integer.
+    UInt(u64),
+    /// A negative integer.
+    Int(i64),
+    F32(f32),
+    F64(f64),
+    String(String),
+    Binary(Vec<u8>),
+    Array(Vec<MsgpackValue>),
+    /// The key-value pairs of a map, in the order they appear in the data.
+    Map(Vec<(MsgpackValue, MsgpackValue)>),
+    /// An extension type, consisting of its type id and its data.
+    Ext(i8, Vec<u8>),
+}
+
+impl TryFrom<Value> for MsgpackValue {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self> {
+        Ok(match value {
+            Value::Nil => Self::Nil,
+            Value::Boolean(value) => Self::Boolean(value),
+            Value::Integer(value) => match value.as_u64() {
+                Some(value) => Self::UInt(value),
+                None => Self::Int(value.as_i64().unwrap_or_default()),
+            },
+            Value::F32(value) => Self::F32(value),
+            Value::F64(value) => Self::F64(value),
+            Value::String(value) => Self::String(value.into_str().ok_or_else(|| {
+                Error::Other("A MessagePack string was not valid UTF-8.".to_string())
+            })?),
+            Value::Binary(value) => Self::Binary(value),
+            Value::Array(values) => Self::Array(
+                values
+                    .into_iter()
+                    .map(Self::try_from)
+                    .collect::<Result<_>>()?,
+            ),
+            Value::Map(pairs) => Self::Map(
+                pairs
+                    .into_iter()
+                    .map(|(key, value)| Ok((Self::try_from(key)?, Self::try_from(value)?)))
+                    .collect::<Result<_>>()?,
+            ),
+            Value::Ext(type_id, data) => Self::Ext(type_id, data),
+        })
+    }
+}
+
+/// Decodes a single MessagePack value from the start of `data`, returning it along with how many
+/// bytes it took up.
+pub(crate) fn decode(data: &[u8]) -> Result<(MsgpackValue, usize)> {
+    let mut remaining = data;
+    let value = rmpv::decode::read_value(&mut remaining)
+        .map_err(|e| Error::Other(format!("Failed to decode MessagePack data: {}", e)))?;
+    Ok((MsgpackValue::try_from(value)?, data.len() - remaining.len()))
+}
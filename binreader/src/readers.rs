@@ -1,10 +1,86 @@
+/// Generates the `next_u16`-style methods (which use the default endidness) for the readers that
+/// can't implement [`BinReader`](crate::BinReader), along with the matching `write_u16`-style
+/// methods for [`BinWriter`](crate::BinWriter). Since `self` can't be passed in to a macro,
+/// there's a separate arm for each kind of receiver: `&mut` and `&` generate inherent methods,
+/// while `async` and `write` generate default trait methods.
+macro_rules! default_endidness_methods {
+    (&mut $reader:ident; $($type:ident: $name:ident, $be:ident, $le:ident;)+) => {
+        $(
+            #[doc = concat!("Gets the `", stringify!($type), "` using the default endidness at ")]
+            #[doc = concat!("the [`", stringify!($reader), "::current_offset`] and then advances ")]
+            #[doc = "past it. If the current endidness is [`Endidness::Unknown`], then an error "]
+            #[doc = "is returned."]
+            pub fn $name(&mut self) -> Result<$type> {
+                match self.endidness() {
+                    Endidness::Big => self.$be(),
+                    Endidness::Little => self.$le(),
+                    Endidness::Unknown => Err(Error::UnknownEndidness),
+                }
+            }
+        )+
+    };
+    (&$reader:ident; $($type:ident: $name:ident, $be:ident, $le:ident;)+) => {
+        $(
+            #[doc = concat!("Gets the `", stringify!($type), "` using the default endidness at ")]
+            #[doc = concat!("the [`", stringify!($reader), "::current_offset`] and then advances ")]
+            #[doc = "past it. If the current endidness is [`Endidness::Unknown`], then an error "]
+            #[doc = "is returned."]
+            pub fn $name(&self) -> Result<$type> {
+                match self.endidness() {
+                    Endidness::Big => self.$be(),
+                    Endidness::Little => self.$le(),
+                    Endidness::Unknown => Err(Error::UnknownEndidness),
+                }
+            }
+        )+
+    };    (async $reader:ident; $($type:ident: $name:ident, $be:ident, $le:ident;)+) => {
+        $(
+            #[doc = concat!("Gets the `", stringify!($type), "` using the default endidness at ")]
+            #[doc = concat!("the [`", stringify!($reader), "::current_offset`] and then advances ")]
+            #[doc = "past it. If the current endidness is [`Endidness::Unknown`], then an error "]
+            #[doc = "is returned."]
+            async fn $name(&mut self) -> Result<$type> {
+                match self.endidness() {
+                    Endidness::Big => self.$be().await,
+                    Endidness::Little => self.$le().await,
+                    Endidness::Unknown => Err(Error::UnknownEndidness),
+                }
+            }
+        )+
+    };
+    (write $writer:ident; $($type:ident: $name:ident, $be:ident, $le:ident;)+) => {
+        $(
+            #[doc = concat!("Writes a `", stringify!($type), "` using the default endidness at ")]
+            #[doc = concat!("the [`", stringify!($writer), "::current_offset`] and then advances ")]
+            #[doc = "past it. If the current endidness is [`Endidness::Unknown`], then an error "]
+            #[doc = "is returned."]
+            fn $name(&mut self, value: $type) -> Result<()> {
+                match self.endidness() {
+                    Endidness::Big => self.$be(value),
+                    Endidness::Little => self.$le(value),
+                    Endidness::Unknown => Err(Error::UnknownEndidness),
+                }
+            }
+        )+
+    };
+}
+
+mod chained;
 mod random_access;
 mod slice;
+mod streaming;
 
+pub use chained::ChainedBinReader;
 pub use random_access::RandomAccessBinReader;
 pub use slice::SliceRefBinReader;
+pub use streaming::StreamingBinReader;
 
 #[cfg(feature = "memmap")]
 mod mmap;
 #[cfg(feature = "memmap")]
 pub use mmap::MmapBinReader;
+
+#[cfg(feature = "tokio")]
+mod stream;
+#[cfg(feature = "tokio")]
+pub use stream::StreamBinReader;
@@ -0,0 +1,324 @@
+use crate::{util, Endidness, Error, Result};
+use binreader_macros::make_number_methods;
+use bytes::Bytes;
+use std::{borrow::Cow, cell::Cell};
+
+/// A reader that presents several segments of data (such as the parts of a split archive) as
+/// one contiguous range of offsets, with offset `N` mapping into whichever segment contains it.
+/// All of the segments share the same endidness.
+///
+/// Since the data isn't stored contiguously, this can't implement
+/// [`BinReader`](crate::BinReader), but it provides the same kinds of methods. Reads that fall
+/// within a single segment borrow from it, while reads that straddle a boundary between segments
+/// are stitched together into an owned buffer, which is why [`ChainedBinReader::subseq`] and
+/// [`ChainedBinReader::next_n_bytes`] return a [`Cow`].
+#[derive(Clone)]
+pub struct ChainedBinReader {
+    initial_offset: usize,
+    position: Cell<usize>,
+    segments: Vec<Bytes>,
+    /// The offset of the start of each segment, relative to the initial offset.
+    starts: Vec<usize>,
+    size: usize,
+    endidness: Endidness,
+}
+
+impl ChainedBinReader {
+    /// Creates a new [`ChainedBinReader`] over the provided segments, in order, whose initial
+    /// offset is `0`.
+    pub fn from_segments(segments: Vec<Bytes>, endidness: Endidness) -> Result<Self> {
+        Self::from_segments_with_offset(segments, 0, endidness)
+    }
+
+    /// Creates a new [`ChainedBinReader`] over the provided segments, in order, where the first
+    /// byte of the first segment is at `initial_offset`.
+    pub fn from_segments_with_offset(
+        segments: Vec<Bytes>,
+        initial_offset: usize,
+        endidness: Endidness,
+    ) -> Result<Self> {
+        let mut reader = Self {
+            initial_offset,
+            position: Cell::new(0),
+            segments: Vec::with_capacity(segments.len()),
+            starts: Vec::with_capacity(segments.len()),
+            size: 0,
+            endidness,
+        };
+        for segment in segments {
+            reader.push_segment(segment)?;
+        }
+        Ok(reader)
+    }
+
+    /// Adds another segment to the end of the reader, extending its
+    /// [`ChainedBinReader::upper_offset_limit`].
+    pub fn push_segment(&mut self, segment: Bytes) -> Result<()> {
+        let size = self
+            .size
+            .checked_add(segment.len())
+            .ok_or(Error::OffsetTooLarge(usize::MAX))?;
+        util::validate_initial_offset(self.initial_offset, size)?;
+        self.starts.push(self.size);
+        self.segments.push(segment);
+        self.size = size;
+        Ok(())
+    }
+
+    /// The segments that make up the reader.
+    #[inline]
+    pub fn segments(&self) -> &[Bytes] {
+        &self.segments
+    }
+
+    /// The offset of the first byte of the first segment.
+    #[inline]
+    pub fn initial_offset(&self) -> usize {
+        self.initial_offset
+    }
+
+    /// The offset of the next byte to be read.
+    #[inline]
+    pub fn current_offset(&self) -> usize {
+        self.initial_offset + self.position.get()
+    }
+
+    /// The combined size of all of the segments.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The number of bytes between the [`ChainedBinReader::current_offset`] and the end of the
+    /// last segment.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.size - self.position.get()
+    }
+
+    /// One more than the offset of the last byte of the last segment.
+    #[inline]
+    pub fn upper_offset_limit(&self) -> usize {
+        self.initial_offset + self.size
+    }
+
+    /// The default endidness used when reading numbers.
+    #[inline]
+    pub fn endidness(&self) -> Endidness {
+        self.endidness
+    }
+
+    /// Changes the default endidness.
+    pub fn change_endidness(&mut self, endidness: Endidness) {
+        self.endidness = endidness;
+    }
+
+    /// Makes sure that `size` bytes starting at `offset` are within the reader. The errors
+    /// returned are the same as those of
+    /// [`BinReader::validate_offset`](crate::BinReader::validate_offset).
+    pub fn validate_offset(&self, offset: usize, size: usize) -> Result<()> {
+        if size > 0 && offset == self.upper_offset_limit() {
+            Err(Error::NoMoreData)
+        } else if offset < self.initial_offset {
+            Err(Error::OffsetTooSmall(offset))
+        } else if offset > self.upper_offset_limit() {
+            Err(Error::OffsetTooLarge(offset))
+        } else if size > self.upper_offset_limit() - offset {
+            Err(Error::NotEnoughData(
+                size,
+                self.upper_offset_limit() - offset,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Sets the [`ChainedBinReader::current_offset`] to the provided offset.
+    pub fn advance_to(&self, offset: usize) -> Result<()> {
+        self.validate_offset(offset, 0)?;
+        self.position.set(offset - self.initial_offset);
+        Ok(())
+    }
+
+    /// Alters the [`ChainedBinReader::current_offset`] by the provided amount.
+    pub fn advance_by(&self, num_bytes: isize) -> Result<()> {
+        self.advance_to(util::offset_by(self.current_offset(), num_bytes)?)
+    }
+
+    /// Gets `num_bytes` bytes starting at the provided offset without altering the
+    /// [`ChainedBinReader::current_offset`]. If they're all in one segment, then they're borrowed
+    /// from it; otherwise, they're copied into a new buffer.
+    pub fn subseq(&self, offset: usize, num_bytes: usize) -> Result<Cow<'_, [u8]>> {
+        self.validate_offset(offset, num_bytes)?;
+        if num_bytes == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+        let start = offset - self.initial_offset;
+        let mut index = self.starts.partition_point(|&seg_start| seg_start <= start) - 1;
+        let seg_offset = start - self.starts[index];
+        let segment = &self.segments[index];
+        if seg_offset + num_bytes <= segment.len() {
+            return Ok(Cow::Borrowed(&segment[seg_offset..seg_offset + num_bytes]));
+        }
+        let mut data = Vec::with_capacity(num_bytes);
+        data.extend_from_slice(&segment[seg_offset..]);
+        while data.len() < num_bytes {
+            index += 1;
+            let needed = num_bytes - data.len();
+            let segment = &self.segments[index];
+            data.extend_from_slice(&segment[..needed.min(segment.len())]);
+        }
+        Ok(Cow::Owned(data))
+    }
+
+    /// Fills the provided buffer with bytes, starting at the provided offset. This does not alter
+    /// the [`ChainedBinReader::current_offset`].
+    pub fn bytes_at(&self, offset: usize, buf: &mut [u8]) -> Result<()> {
+        buf.copy_from_slice(&self.subseq(offset, buf.len())?);
+        Ok(())
+    }
+
+    /// Gets the next `num_bytes` bytes (see [`ChainedBinReader::subseq`]) and then advances the
+    /// [`ChainedBinReader::current_offset`] past them.
+    pub fn next_n_bytes(&self, num_bytes: usize) -> Result<Cow<'_, [u8]>> {
+        let data = self.subseq(self.current_offset(), num_bytes)?;
+        self.advance_by(num_bytes as isize)?;
+        Ok(data)
+    }
+
+    /// Fills the provided buffer with the next bytes, and then advances the
+    /// [`ChainedBinReader::current_offset`] past them.
+    pub fn next_bytes(&self, buf: &mut [u8]) -> Result<()> {
+        self.bytes_at(self.current_offset(), buf)?;
+        self.advance_by(buf.len() as isize)
+    }
+
+    /// Gets the `u8` at the provided offset without altering the
+    /// [`ChainedBinReader::current_offset`].
+    pub fn u8_at(&self, offset: usize) -> Result<u8> {
+        Ok(self.subseq(offset, 1)?[0])
+    }
+
+    /// Gets the `i8` at the provided offset without altering the
+    /// [`ChainedBinReader::current_offset`].
+    pub fn i8_at(&self, offset: usize) -> Result<i8> {
+        Ok(self.u8_at(offset)? as i8)
+    }
+
+    /// Gets the next `u8` and then advances the [`ChainedBinReader::current_offset`] by `1`.
+    pub fn next_u8(&self) -> Result<u8> {
+        let value = self.u8_at(self.current_offset())?;
+        self.advance_by(1)?;
+        Ok(value)
+    }
+
+    /// Gets the next `i8` and then advances the [`ChainedBinReader::current_offset`] by `1`.
+    pub fn next_i8(&self) -> Result<i8> {
+        Ok(self.next_u8()? as i8)
+    }
+
+    make_number_methods! {
+        /// Gets the numendlong endian `numname` at the provided offset without altering the
+        /// [`ChainedBinReader::current_offset`].
+        pub fn numname_numend_at(&self, offset: usize) -> Result<_numname_> {
+            let mut buf = [0; _numwidth_];
+            self.bytes_at(offset, &mut buf)?;
+            Ok(_numname_::from_numend_bytes(buf))
+        }
+    }
+
+    make_number_methods! {
+        /// Gets the next numendlong endian `numname` and then advances the
+        /// [`ChainedBinReader::current_offset`] past it.
+        pub fn next_numname_numend(&self) -> Result<_numname_> {
+            let mut buf = [0; _numwidth_];
+            self.next_bytes(&mut buf)?;
+            Ok(_numname_::from_numend_bytes(buf))
+        }
+    }
+
+    default_endidness_methods! {
+        &ChainedBinReader;
+        u16: next_u16, next_u16_be, next_u16_le;
+        u32: next_u32, next_u32_be, next_u32_le;
+        u64: next_u64, next_u64_be, next_u64_le;
+        u128: next_u128, next_u128_be, next_u128_le;
+        i16: next_i16, next_i16_be, next_i16_le;
+        i32: next_i32, next_i32_be, next_i32_le;
+        i64: next_i64, next_i64_be, next_i64_le;
+        i128: next_i128, next_i128_be, next_i128_le;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TEST_DATA;
+
+    fn split_test_data(offset: usize) -> ChainedBinReader {
+        ChainedBinReader::from_segments_with_offset(
+            vec![
+                Bytes::from_static(&TEST_DATA[..3]),
+                Bytes::new(),
+                Bytes::from_static(&TEST_DATA[3..4]),
+                Bytes::from_static(&TEST_DATA[4..10]),
+                Bytes::from_static(&TEST_DATA[10..]),
+            ],
+            offset,
+            Endidness::Big,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn chained_reader_test() {
+        let reader = split_test_data(5);
+        assert_eq!(reader.size(), TEST_DATA.len());
+        assert_eq!(reader.upper_offset_limit(), 21);
+        for i in 0..TEST_DATA.len() {
+            assert_eq!(reader.u8_at(i + 5).unwrap(), TEST_DATA[i]);
+        }
+        assert!(
+            matches!(reader.subseq(9, 6).unwrap(), Cow::Borrowed(data) if data == &TEST_DATA[4..10])
+        );
+        assert!(
+            matches!(reader.subseq(6, 8).unwrap(), Cow::Owned(data) if data == TEST_DATA[1..9])
+        );
+        assert_eq!(reader.subseq(5, 16).unwrap(), &TEST_DATA[..]);
+        assert_eq!(reader.next_u16().unwrap(), 0x0001);
+        assert_eq!(reader.next_u32_le().unwrap(), 0x05040302);
+        assert_eq!(reader.current_offset(), 11);
+        assert_eq!(reader.next_n_bytes(6).unwrap(), &TEST_DATA[6..12]);
+        assert_eq!(reader.remaining(), 4);
+        assert!(matches!(reader.next_u64(), Err(Error::NotEnoughData(8, 4))));
+        assert_eq!(reader.current_offset(), 17);
+        assert_eq!(reader.next_i32_be().unwrap(), 0x0c0d0e0f);
+        assert!(matches!(reader.next_u8(), Err(Error::NoMoreData)));
+        assert!(matches!(reader.u8_at(4), Err(Error::OffsetTooSmall(4))));
+        reader.advance_by(-16).unwrap();
+        assert_eq!(
+            reader.next_u128_le().unwrap(),
+            u128::from_le_bytes(TEST_DATA)
+        );
+    }
+
+    #[test]
+    fn chained_reader_segments_test() {
+        let mut reader = ChainedBinReader::from_segments(Vec::new(), Endidness::Little).unwrap();
+        assert_eq!(reader.size(), 0);
+        assert!(matches!(reader.next_u8(), Err(Error::NoMoreData)));
+        reader.push_segment(Bytes::from_static(&[0x01])).unwrap();
+        reader.push_segment(Bytes::from_static(&[0x02])).unwrap();
+        assert_eq!(reader.segments().len(), 2);
+        assert_eq!(reader.next_u16().unwrap(), 0x0201);
+        reader.change_endidness(Endidness::Unknown);
+        reader.advance_to(0).unwrap();
+        assert!(matches!(reader.next_u16(), Err(Error::UnknownEndidness)));
+        assert!(ChainedBinReader::from_segments_with_offset(
+            vec![Bytes::from_static(&TEST_DATA)],
+            usize::MAX - 1,
+            Endidness::Big,
+        )
+        .is_err());
+    }
+}
@@ -1,15 +1,27 @@
-use crate::{BinReader, Endidness, OwnableBinReader, Result};
+use crate::{regions::RegionTracker, util, BinReader, Endidness, OwnableBinReader, Result};
 use bytes::Bytes;
-use fs3::FileExt as _;
+use fs3::FileExt;
 use memmap2::{Mmap, MmapMut};
-use std::{cell::Cell, fs::File, path::Path};
+use std::{cell::Cell, fs::File, ops::Range, path::Path};
 
 pub struct MmapBinReader {
     initial_offset: usize,
     position: Cell<usize>,
     map: Mmap,
     endidness: Endidness,
-    maybe_mapped_file: Option<File>,
+    regions: RegionTracker,
+    monotonic: Cell<bool>,
+    // Only held so that the file stays locked for as long as it's mapped.
+    _file_lock: Option<LockedFile>,
+}
+
+/// Holds the shared lock on a mapped file, releasing it when dropped.
+struct LockedFile(File);
+
+impl Drop for LockedFile {
+    fn drop(&mut self) {
+        FileExt::unlock(&self.0).unwrap();
+    }
 }
 
 impl MmapBinReader {
@@ -17,14 +29,16 @@ impl MmapBinReader {
         initial_offset: usize,
         map: Mmap,
         endidness: Endidness,
-        maybe_mapped_file: Option<File>,
+        file_lock: Option<LockedFile>,
     ) -> Self {
         Self {
             initial_offset,
             position: Cell::new(0),
             map,
             endidness,
-            maybe_mapped_file,
+            regions: RegionTracker::default(),
+            monotonic: Cell::new(false),
+            _file_lock: file_lock,
         }
     }
 
@@ -32,13 +46,11 @@ impl MmapBinReader {
         let tmp = self.position.get() as isize;
         self.position.replace((tmp + amt) as usize);
     }
-}
 
-impl Drop for MmapBinReader {
-    fn drop(&mut self) {
-        if let Some(file) = &self.maybe_mapped_file {
-            file.unlock().unwrap();
-        }
+    /// Consumes the reader, returning the underlying memory map. If the reader was created from
+    /// a file, then the lock it held on that file is released.
+    pub fn into_mmap(self) -> Mmap {
+        self.map
     }
 }
 
@@ -84,20 +96,53 @@ impl<'r> BinReader<'r> for MmapBinReader {
         self.initial_offset
     }
 
+    #[inline]
+    fn enable_region_tracking(&self) {
+        self.regions.enable()
+    }
+
+    #[inline]
+    fn label_region(&self, name: &str, start: usize, end: usize) {
+        self.regions.label(name, start, end)
+    }
+
+    #[inline]
+    fn regions(&self) -> Vec<(String, Range<usize>)> {
+        self.regions.regions()
+    }
+
+    #[inline]
+    fn enable_monotonic_check(&self) {
+        self.monotonic.set(true)
+    }
+
+    #[inline]
+    fn disable_monotonic_check(&self) {
+        self.monotonic.set(false)
+    }
+
+    #[inline]
+    fn monotonic_check_enabled(&self) -> bool {
+        self.monotonic.get()
+    }
+
     fn advance_to(&self, offset: usize) -> Result<()> {
         self.validate_offset(offset, 0)?;
+        util::check_monotonic(self.monotonic.get(), self.current_offset(), offset)?;
         self.position.replace(offset - self.initial_offset);
         Ok(())
     }
 
     fn advance_by(&self, num_bytes: isize) -> Result<()> {
-        self.validate_offset((self.current_offset() as isize + num_bytes) as usize, 0)?;
+        let offset = util::offset_by(self.current_offset(), num_bytes)?;
+        self.validate_offset(offset, 0)?;
+        util::check_monotonic(self.monotonic.get(), self.current_offset(), offset)?;
         self.adj_pos(num_bytes);
         Ok(())
     }
 
     fn u8_at(&self, offset: usize) -> Result<u8> {
-        self.validate_offset(offset, 0)?;
+        self.validate_offset(offset, 1)?;
         Ok(self.map[offset - self.initial_offset])
     }
 
@@ -112,6 +157,7 @@ impl<'r> BinReader<'r> for MmapBinReader {
         initial_offset: usize,
         endidness: Endidness,
     ) -> Result<Self> {
+        util::validate_initial_offset(initial_offset, slice.len())?;
         let mut mmap_mut = MmapMut::map_anon(slice.len())?;
         mmap_mut.copy_from_slice(slice);
         Ok(Self::new(
@@ -130,9 +176,15 @@ impl<'r> OwnableBinReader<'r> for MmapBinReader {
         endidness: Endidness,
     ) -> Result<Self> {
         let file = File::open(path)?;
-        file.try_lock_shared()?;
+        FileExt::try_lock_shared(&file)?;
         let mmap = unsafe { Mmap::map(&file)? };
-        Ok(Self::new(initial_offset, mmap, endidness, Some(file)))
+        util::validate_initial_offset(initial_offset, mmap.len())?;
+        Ok(Self::new(
+            initial_offset,
+            mmap,
+            endidness,
+            Some(LockedFile(file)),
+        ))
     }
 
     fn from_bytes_with_offset(
@@ -155,5 +207,14 @@ add_all_noms! { MmapBinReader }
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TEST_DATA;
+
     test_reader! { MmapBinReader }
+
+    #[test]
+    fn into_mmap_test() {
+        let reader = MmapBinReader::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+        reader.advance_by(4).unwrap();
+        assert_eq!(reader.into_mmap().as_ref(), &TEST_DATA);
+    }
 }
@@ -1,12 +1,19 @@
-use crate::{util::bytes_from_file, BinReader, Endidness, OwnableBinReader, Result};
+use crate::{
+    regions::RegionTracker,
+    util::{self, bytes_from_file},
+    BinReader, Endidness, Error, OwnableBinReader, Result,
+};
 use bytes::Bytes;
-use std::{cell::Cell, path::Path};
+use std::{cell::Cell, ops::Range, path::Path};
 
+#[derive(Clone)]
 pub struct RandomAccessBinReader {
     initial_offset: usize,
     position: Cell<usize>,
     data: Bytes,
     endidness: Endidness,
+    regions: RegionTracker,
+    monotonic: Cell<bool>,
 }
 
 impl RandomAccessBinReader {
@@ -17,6 +24,8 @@ impl RandomAccessBinReader {
             position: Cell::new(0),
             data,
             endidness,
+            regions: RegionTracker::default(),
+            monotonic: Cell::new(false),
         }
     }
 
@@ -24,6 +33,57 @@ impl RandomAccessBinReader {
         let tmp = self.position.get() as isize;
         self.position.replace((tmp + amt) as usize);
     }
+
+    /// Consumes the reader, returning the underlying data.
+    #[inline]
+    pub fn into_inner(self) -> Bytes {
+        self.data
+    }
+
+    /// The owned counterpart to [`BinReader::slice_reader_with_offset`]. This returns a new
+    /// [`RandomAccessBinReader`] over the data between the provided starting and ending offsets
+    /// that shares (rather than copies) this reader's data. The sub-reader's initial offset is
+    /// `start`.
+    pub fn subreader(&self, start: usize, end: usize) -> Result<RandomAccessBinReader> {
+        let size = end.checked_sub(start).ok_or(Error::OffsetTooSmall(end))?;
+        self.validate_offset(start, size)?;
+        let data_start = start - self.initial_offset;
+        Ok(Self::new(
+            self.data.slice(data_start..data_start + size),
+            start,
+            self.endidness,
+        ))
+    }
+
+    /// The owned counterpart to [`BinReader::next_n_bytes`]. This returns a [`Bytes`] instance that
+    /// shares (rather than copies) the next `num_bytes` of this reader's data, so it can outlive
+    /// the borrow of this reader, and then advances the [`BinReader::current_offset`] past them.
+    pub fn next_n_bytes_bytes(&self, num_bytes: usize) -> Result<Bytes> {
+        self.validate_offset(self.current_offset(), num_bytes)?;
+        let data_start = self.position.get();
+        let data = self.data.slice(data_start..data_start + num_bytes);
+        self.advance_by(num_bytes as isize)?;
+        Ok(data)
+    }
+
+    /// The owned counterpart to [`BinReader::next_sized_reader_u32`]. This reads a `u32` length
+    /// using the default endidness, then returns a new [`RandomAccessBinReader`] that shares
+    /// (rather than copies) the following bytes, so it can outlive the borrow of this reader. The
+    /// sub-reader's initial offset is the offset of the first byte after the length, and the
+    /// [`BinReader::current_offset`] is advanced past the whole block.
+    pub fn next_sized_owned_reader_u32(&self) -> Result<RandomAccessBinReader> {
+        let len = self.u32_at(self.current_offset())? as usize;
+        let start = self.current_offset() + 4;
+        self.validate_offset(start, len)?;
+        let data_start = start - self.initial_offset;
+        let reader = Self::new(
+            self.data.slice(data_start..data_start + len),
+            start,
+            self.endidness,
+        );
+        self.advance_to(start + len)?;
+        Ok(reader)
+    }
 }
 
 impl AsRef<[u8]> for RandomAccessBinReader {
@@ -83,14 +143,47 @@ impl<'r> BinReader<'r> for RandomAccessBinReader {
         self.endidness = endidness
     }
 
+    #[inline]
+    fn enable_region_tracking(&self) {
+        self.regions.enable()
+    }
+
+    #[inline]
+    fn label_region(&self, name: &str, start: usize, end: usize) {
+        self.regions.label(name, start, end)
+    }
+
+    #[inline]
+    fn regions(&self) -> Vec<(String, Range<usize>)> {
+        self.regions.regions()
+    }
+
+    #[inline]
+    fn enable_monotonic_check(&self) {
+        self.monotonic.set(true)
+    }
+
+    #[inline]
+    fn disable_monotonic_check(&self) {
+        self.monotonic.set(false)
+    }
+
+    #[inline]
+    fn monotonic_check_enabled(&self) -> bool {
+        self.monotonic.get()
+    }
+
     fn advance_to(&self, offset: usize) -> Result<()> {
         self.validate_offset(offset, 0)?;
+        util::check_monotonic(self.monotonic.get(), self.current_offset(), offset)?;
         self.position.replace(offset - self.initial_offset);
         Ok(())
     }
 
     fn advance_by(&self, num_bytes: isize) -> Result<()> {
-        self.validate_offset((self.current_offset() as isize + num_bytes) as usize, 0)?;
+        let offset = util::offset_by(self.current_offset(), num_bytes)?;
+        self.validate_offset(offset, 0)?;
+        util::check_monotonic(self.monotonic.get(), self.current_offset(), offset)?;
         self.adj_pos(num_bytes);
         Ok(())
     }
@@ -109,7 +202,7 @@ impl<'r> OwnableBinReader<'r> for RandomAccessBinReader {
         initial_offset: usize,
         endidness: Endidness,
     ) -> Result<Self> {
-        Ok(Self::new(bytes_from_file(path)?, initial_offset, endidness))
+        Self::from_bytes_with_offset(bytes_from_file(path)?, initial_offset, endidness)
     }
 
     #[inline]
@@ -118,6 +211,7 @@ impl<'r> OwnableBinReader<'r> for RandomAccessBinReader {
         initial_offset: usize,
         endidness: Endidness,
     ) -> Result<Self> {
+        util::validate_initial_offset(initial_offset, bytes.len())?;
         Ok(Self::new(bytes, initial_offset, endidness))
     }
 }
@@ -133,6 +227,126 @@ add_all_noms! { RandomAccessBinReader }
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TEST_DATA;
+    use std::io::Read as _;
 
     test_reader! { RandomAccessBinReader }
+
+    #[test]
+    fn clone_test() {
+        crate::testing::clone_test::<RandomAccessBinReader>();
+    }
+
+    #[test]
+    fn into_inner_test() {
+        let bytes = Bytes::from_static(&TEST_DATA);
+        let reader = RandomAccessBinReader::from_bytes(bytes.clone(), Endidness::Big).unwrap();
+        reader.advance_by(4).unwrap();
+        let data = reader.into_inner();
+        assert_eq!(data, bytes);
+        assert_eq!(data.as_ptr(), bytes.as_ptr());
+    }
+
+    #[test]
+    fn from_vec_test() {
+        let data = TEST_DATA.to_vec();
+        let ptr = data.as_ptr();
+        let reader = RandomAccessBinReader::from_vec(data, Endidness::Big).unwrap();
+        assert_eq!(reader.as_ref().as_ptr(), ptr);
+        assert_eq!(reader.as_ref(), &TEST_DATA);
+        let reader =
+            RandomAccessBinReader::from_vec_with_offset(TEST_DATA.to_vec(), 10, Endidness::Big)
+                .unwrap();
+        assert_eq!(reader.initial_offset(), 10);
+        assert_eq!(reader.u8_at(10).unwrap(), TEST_DATA[0]);
+    }
+
+    #[test]
+    fn from_reader_test() {
+        let reader = RandomAccessBinReader::from_reader(&TEST_DATA[..], Endidness::Little).unwrap();
+        assert_eq!(reader.as_ref(), &TEST_DATA);
+        let source = (&TEST_DATA[..4]).chain(&TEST_DATA[4..]);
+        let reader =
+            RandomAccessBinReader::from_reader_with_offset(source, 5, Endidness::Little).unwrap();
+        assert_eq!(reader.initial_offset(), 5);
+        assert_eq!(reader.as_ref(), &TEST_DATA);
+        assert!(matches!(
+            RandomAccessBinReader::from_reader_with_offset(&TEST_DATA[..0], 0, Endidness::Big),
+            Ok(reader) if reader.is_empty()
+        ));
+    }
+
+    #[test]
+    fn next_n_bytes_bytes_test() {
+        let bytes = Bytes::from_static(&TEST_DATA);
+        let reader =
+            RandomAccessBinReader::from_bytes_with_offset(bytes.clone(), 4, Endidness::Big)
+                .unwrap();
+        let first = reader.next_n_bytes_bytes(5).unwrap();
+        assert_eq!(first, &TEST_DATA[..5]);
+        assert_eq!(first.as_ptr(), bytes.as_ptr());
+        assert_eq!(reader.current_offset(), 9);
+        let second = reader.next_n_bytes_bytes(5).unwrap();
+        drop(reader);
+        assert_eq!(second, &TEST_DATA[5..10]);
+        assert_eq!(second.as_ptr(), bytes[5..].as_ptr());
+
+        let reader = RandomAccessBinReader::from_bytes(bytes, Endidness::Big).unwrap();
+        assert!(reader.next_n_bytes_bytes(0).unwrap().is_empty());
+        assert!(matches!(
+            reader.next_n_bytes_bytes(17),
+            Err(Error::NotEnoughData(17, 16))
+        ));
+        assert_eq!(reader.current_offset(), 0);
+    }
+
+    #[test]
+    fn next_sized_owned_reader_u32_test() {
+        let reader = RandomAccessBinReader::from_bytes_with_offset(
+            Bytes::from_static(&[
+                0x00, 0x00, 0x00, 0x03, 0xaa, 0xbb, 0xcc, 0x00, 0x00, 0x00, 0x09,
+            ]),
+            10,
+            Endidness::Big,
+        )
+        .unwrap();
+        let block = reader.next_sized_owned_reader_u32().unwrap();
+        assert_eq!(reader.current_offset(), 17);
+        drop(reader);
+        assert_eq!(block.initial_offset(), 14);
+        assert_eq!(block.as_ref(), &[0xaa, 0xbb, 0xcc]);
+        assert_eq!(block.next_u16().unwrap(), 0xaabb);
+        assert_eq!(block.current_offset(), 16);
+
+        let reader = RandomAccessBinReader::from_bytes(
+            Bytes::from_static(&[0x00, 0x00, 0x00, 0x09, 0xaa]),
+            Endidness::Big,
+        )
+        .unwrap();
+        assert!(matches!(
+            reader.next_sized_owned_reader_u32(),
+            Err(Error::NotEnoughData(9, 1))
+        ));
+        assert_eq!(reader.current_offset(), 0);
+    }
+
+    #[test]
+    fn subreader_test() {
+        let reader =
+            RandomAccessBinReader::from_slice_with_offset(&TEST_DATA, 10, Endidness::Big).unwrap();
+        let sub = reader.subreader(12, 16).unwrap();
+        assert_eq!(sub.initial_offset(), 12);
+        assert_eq!(sub.as_ref(), &[0x02, 0x03, 0x04, 0x05]);
+        assert_eq!(sub.as_ref().as_ptr(), reader.as_ref()[2..].as_ptr());
+        assert_eq!(sub.next_u16().unwrap(), 0x0203);
+        assert!(matches!(
+            reader.subreader(12, 30),
+            Err(Error::NotEnoughData(18, 14))
+        ));
+        assert!(matches!(
+            reader.subreader(14, 12),
+            Err(Error::OffsetTooSmall(12))
+        ));
+        assert_eq!(reader.current_offset(), 10);
+    }
 }
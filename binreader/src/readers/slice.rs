@@ -1,12 +1,15 @@
-use crate::{BinReader, Endidness, Result};
-use std::cell::Cell;
+use crate::{regions::RegionTracker, util, BinReader, Endidness, Result};
+use std::{cell::Cell, ops::Range};
 
 /// A [`SliceRefBinReader`]
+#[derive(Clone)]
 pub struct SliceRefBinReader<'r> {
     initial_offset: usize,
     position: Cell<usize>,
     data: &'r [u8],
     endidness: Endidness,
+    regions: RegionTracker,
+    monotonic: Cell<bool>,
 }
 
 impl<'r> SliceRefBinReader<'r> {
@@ -17,6 +20,8 @@ impl<'r> SliceRefBinReader<'r> {
             position: Cell::new(0),
             data,
             endidness,
+            regions: RegionTracker::default(),
+            monotonic: Cell::new(false),
         }
     }
 
@@ -24,6 +29,12 @@ impl<'r> SliceRefBinReader<'r> {
         let tmp = self.position.get() as isize;
         self.position.replace((tmp + amt) as usize);
     }
+
+    /// Consumes the reader, returning the slice it was reading from.
+    #[inline]
+    pub fn into_slice(self) -> &'r [u8] {
+        self.data
+    }
 }
 
 impl<'r> AsRef<[u8]> for SliceRefBinReader<'r> {
@@ -42,6 +53,7 @@ where
         initial_offset: usize,
         endidness: Endidness,
     ) -> Result<Self> {
+        util::validate_initial_offset(initial_offset, slice.len())?;
         Ok(Self::new(slice, initial_offset, endidness))
     }
 
@@ -80,14 +92,47 @@ where
         self.endidness = endidness
     }
 
+    #[inline]
+    fn enable_region_tracking(&self) {
+        self.regions.enable()
+    }
+
+    #[inline]
+    fn label_region(&self, name: &str, start: usize, end: usize) {
+        self.regions.label(name, start, end)
+    }
+
+    #[inline]
+    fn regions(&self) -> Vec<(String, Range<usize>)> {
+        self.regions.regions()
+    }
+
+    #[inline]
+    fn enable_monotonic_check(&self) {
+        self.monotonic.set(true)
+    }
+
+    #[inline]
+    fn disable_monotonic_check(&self) {
+        self.monotonic.set(false)
+    }
+
+    #[inline]
+    fn monotonic_check_enabled(&self) -> bool {
+        self.monotonic.get()
+    }
+
     fn advance_to(&self, offset: usize) -> Result<()> {
         self.validate_offset(offset, 0)?;
+        util::check_monotonic(self.monotonic.get(), self.current_offset(), offset)?;
         self.position.replace(offset - self.initial_offset);
         Ok(())
     }
 
     fn advance_by(&self, num_bytes: isize) -> Result<()> {
-        self.validate_offset((self.current_offset() as isize + num_bytes) as usize, 0)?;
+        let offset = util::offset_by(self.current_offset(), num_bytes)?;
+        self.validate_offset(offset, 0)?;
+        util::check_monotonic(self.monotonic.get(), self.current_offset(), offset)?;
         self.adj_pos(num_bytes);
         Ok(())
     }
@@ -107,5 +152,21 @@ add_bufread! { SliceRefBinReader<'r>, 'r }
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TEST_DATA;
+
     test_reader! { SliceRefBinReader }
+
+    #[test]
+    fn clone_test() {
+        crate::testing::clone_test::<SliceRefBinReader>();
+    }
+
+    #[test]
+    fn into_slice_test() {
+        let reader = SliceRefBinReader::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+        reader.advance_by(4).unwrap();
+        let slice = reader.into_slice();
+        assert_eq!(slice, &TEST_DATA);
+        assert_eq!(slice.as_ptr(), TEST_DATA.as_ptr());
+    }
 }
@@ -0,0 +1,144 @@
+use crate::{AsyncBinReader, Endidness, Error, Result};
+use tokio::io::{AsyncRead, AsyncReadExt as _};
+
+/// An [`AsyncBinReader`] that reads from an [`AsyncRead`], buffering data internally until
+/// enough of it has arrived to satisfy each read.
+pub struct StreamBinReader<R> {
+    inner: R,
+    buffer: Vec<u8>,
+    start: usize,
+    initial_offset: usize,
+    consumed: usize,
+    endidness: Endidness,
+}
+
+impl<R: AsyncRead + Unpin> StreamBinReader<R> {
+    /// Creates a new [`StreamBinReader`] whose initial offset is `0`.
+    pub fn new(inner: R, endidness: Endidness) -> Self {
+        Self::with_offset(inner, 0, endidness)
+    }
+
+    /// Creates a new [`StreamBinReader`] whose first byte is at `initial_offset`.
+    pub fn with_offset(inner: R, initial_offset: usize, endidness: Endidness) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+            start: 0,
+            initial_offset,
+            consumed: 0,
+            endidness,
+        }
+    }
+
+    /// The number of bytes that have been received but not read yet.
+    #[inline]
+    pub fn buffered(&self) -> usize {
+        self.buffer.len() - self.start
+    }
+
+    /// Returns the underlying source. Any data that has been buffered but not read yet is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    async fn fill(&mut self, num_bytes: usize) -> Result<()> {
+        while self.buffered() < num_bytes {
+            if self.start > 0 {
+                self.buffer.drain(..self.start);
+                self.start = 0;
+            }
+            self.buffer.reserve(num_bytes - self.buffered());
+            if self.inner.read_buf(&mut self.buffer).await? == 0 {
+                return Err(match self.buffered() {
+                    0 => Error::NoMoreData,
+                    buffered => Error::NotEnoughData(num_bytes, buffered),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncBinReader for StreamBinReader<R> {
+    #[inline]
+    fn initial_offset(&self) -> usize {
+        self.initial_offset
+    }
+
+    #[inline]
+    fn current_offset(&self) -> usize {
+        self.initial_offset + self.consumed
+    }
+
+    #[inline]
+    fn endidness(&self) -> Endidness {
+        self.endidness
+    }
+
+    fn change_endidness(&mut self, endidness: Endidness) {
+        self.endidness = endidness;
+    }
+
+    async fn next_n_bytes(&mut self, num_bytes: usize) -> Result<&[u8]> {
+        self.fill(num_bytes).await?;
+        let start = self.start;
+        self.start += num_bytes;
+        self.consumed += num_bytes;
+        Ok(&self.buffer[start..self.start])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TEST_DATA;
+    use std::convert::TryInto;
+
+    #[tokio::test]
+    async fn stream_reader_test() {
+        let mut reader = StreamBinReader::with_offset(&TEST_DATA[..], 10, Endidness::Big);
+        assert_eq!(reader.next_u8().await.unwrap(), TEST_DATA[0]);
+        assert_eq!(
+            reader.next_u16().await.unwrap(),
+            u16::from_be_bytes([TEST_DATA[1], TEST_DATA[2]])
+        );
+        assert_eq!(
+            reader.next_u32_le().await.unwrap(),
+            u32::from_le_bytes([TEST_DATA[3], TEST_DATA[4], TEST_DATA[5], TEST_DATA[6]])
+        );
+        assert_eq!(reader.current_offset(), 17);
+        assert_eq!(reader.next_n_bytes(8).await.unwrap(), &TEST_DATA[7..15]);
+        assert!(matches!(
+            reader.next_u16().await,
+            Err(Error::NotEnoughData(2, 1))
+        ));
+        assert_eq!(reader.current_offset(), 25);
+        reader.change_endidness(Endidness::Unknown);
+        assert!(matches!(
+            reader.next_i8().await,
+            Ok(byte) if byte == TEST_DATA[15] as i8
+        ));
+        assert!(matches!(reader.next_u8().await, Err(Error::NoMoreData)));
+    }
+
+    #[tokio::test]
+    async fn stream_reader_spans_reads_test() {
+        let source = (&TEST_DATA[..3]).chain(&TEST_DATA[3..]);
+        let mut reader = StreamBinReader::new(source, Endidness::Unknown);
+        assert!(matches!(
+            reader.next_u32().await,
+            Err(Error::UnknownEndidness)
+        ));
+        reader.change_endidness(Endidness::Little);
+        assert_eq!(
+            reader.next_u64().await.unwrap(),
+            u64::from_le_bytes(TEST_DATA[..8].try_into().unwrap())
+        );
+        assert_eq!(reader.buffered(), 0);
+        assert_eq!(
+            reader.next_i64_be().await.unwrap(),
+            i64::from_be_bytes(TEST_DATA[8..].try_into().unwrap())
+        );
+        assert_eq!(reader.current_offset(), 16);
+    }
+}
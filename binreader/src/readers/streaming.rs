@@ -0,0 +1,333 @@
+use crate::{util, Endidness, Error, OwnableBinReader, RandomAccessBinReader, Result};
+use binreader_macros::make_number_methods;
+use bytes::BytesMut;
+use std::io;
+
+/// A reader over an [`io::Read`] that only pulls data from it as it's needed. Data is buffered
+/// up to the highest offset that's been requested so far, so reading a header from a large
+/// stream doesn't require loading the whole thing, but any offset that has been buffered can be
+/// read again.
+///
+/// Since the total size isn't known until the underlying reader is exhausted,
+/// [`StreamingBinReader::upper_offset_limit`] only reflects the data buffered so far. Because
+/// the buffer can grow while reading, this can't hand out references with the reader's own
+/// lifetime, so it doesn't implement [`BinReader`](crate::BinReader). Instead, it provides the
+/// same methods through `&mut self`, and it can be converted into a
+/// [`RandomAccessBinReader`] via [`StreamingBinReader::into_random_access`].
+pub struct StreamingBinReader<R> {
+    inner: R,
+    buffer: BytesMut,
+    initial_offset: usize,
+    position: usize,
+    endidness: Endidness,
+    exhausted: bool,
+}
+
+impl<R: io::Read> StreamingBinReader<R> {
+    /// Creates a new [`StreamingBinReader`] whose initial offset is `0`.
+    pub fn new(inner: R, endidness: Endidness) -> Self {
+        Self::with_offset(inner, 0, endidness)
+    }
+
+    /// Creates a new [`StreamingBinReader`] whose first byte is at `initial_offset`.
+    pub fn with_offset(inner: R, initial_offset: usize, endidness: Endidness) -> Self {
+        Self {
+            inner,
+            buffer: BytesMut::new(),
+            initial_offset,
+            position: 0,
+            endidness,
+            exhausted: false,
+        }
+    }
+
+    /// Reads from the underlying reader until at least `len` bytes are buffered or it runs out
+    /// of data. The buffer grows by at most its current size (or 4 KiB) per read, so asking for
+    /// far more data than the underlying reader has doesn't allocate all of it up front.
+    fn fill_to(&mut self, len: usize) -> Result<()> {
+        while !self.exhausted && self.buffer.len() < len {
+            let start = self.buffer.len();
+            self.buffer.resize(len.min(start + start.max(4096)), 0);
+            let read = loop {
+                match self.inner.read(&mut self.buffer[start..]) {
+                    Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                    result => break result,
+                }
+            };
+            match read {
+                Ok(read) => {
+                    self.buffer.truncate(start + read);
+                    self.exhausted = read == 0;
+                }
+                Err(error) => {
+                    self.buffer.truncate(start);
+                    return Err(error.into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The offset of the first byte read from the underlying reader.
+    #[inline]
+    pub fn initial_offset(&self) -> usize {
+        self.initial_offset
+    }
+
+    /// The offset of the next byte to be read.
+    #[inline]
+    pub fn current_offset(&self) -> usize {
+        self.initial_offset + self.position
+    }
+
+    /// One more than the offset of the last byte that has been buffered so far.
+    #[inline]
+    pub fn upper_offset_limit(&self) -> usize {
+        self.initial_offset + self.buffer.len()
+    }
+
+    /// The number of bytes that have been buffered so far.
+    #[inline]
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// The total size of the data, which is only known once the underlying reader has run out
+    /// of data. Until then, `None` is returned.
+    #[inline]
+    pub fn size(&self) -> Option<usize> {
+        if self.exhausted {
+            Some(self.buffer.len())
+        } else {
+            None
+        }
+    }
+
+    /// The default endidness used when reading numbers.
+    #[inline]
+    pub fn endidness(&self) -> Endidness {
+        self.endidness
+    }
+
+    /// Changes the default endidness.
+    pub fn change_endidness(&mut self, endidness: Endidness) {
+        self.endidness = endidness;
+    }
+
+    /// Makes sure that `size` bytes starting at `offset` are available, pulling more data from
+    /// the underlying reader if needed. The errors returned are the same as those of
+    /// [`BinReader::validate_offset`](crate::BinReader::validate_offset).
+    pub fn validate_offset(&mut self, offset: usize, size: usize) -> Result<()> {
+        if offset < self.initial_offset {
+            return Err(Error::OffsetTooSmall(offset));
+        }
+        let end = (offset - self.initial_offset)
+            .checked_add(size)
+            .ok_or(Error::OffsetTooLarge(offset))?;
+        self.fill_to(end)?;
+        let upper = self.upper_offset_limit();
+        if size > 0 && offset == upper {
+            Err(Error::NoMoreData)
+        } else if offset > upper {
+            Err(Error::OffsetTooLarge(offset))
+        } else if size > upper - offset {
+            Err(Error::NotEnoughData(size, upper - offset))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Sets the [`StreamingBinReader::current_offset`] to the provided offset, buffering data up
+    /// to it if needed.
+    pub fn advance_to(&mut self, offset: usize) -> Result<()> {
+        self.validate_offset(offset, 0)?;
+        self.position = offset - self.initial_offset;
+        Ok(())
+    }
+
+    /// Alters the [`StreamingBinReader::current_offset`] by the provided amount.
+    pub fn advance_by(&mut self, num_bytes: isize) -> Result<()> {
+        self.advance_to(util::offset_by(self.current_offset(), num_bytes)?)
+    }
+
+    /// Gets a slice of `num_bytes` bytes starting at the provided offset, buffering data up to
+    /// the end of it if needed. This does not alter the [`StreamingBinReader::current_offset`].
+    pub fn subseq(&mut self, offset: usize, num_bytes: usize) -> Result<&[u8]> {
+        self.validate_offset(offset, num_bytes)?;
+        let start = offset - self.initial_offset;
+        Ok(&self.buffer[start..start + num_bytes])
+    }
+
+    /// Fills the provided buffer with bytes, starting at the provided offset. This does not alter
+    /// the [`StreamingBinReader::current_offset`].
+    pub fn bytes_at(&mut self, offset: usize, buf: &mut [u8]) -> Result<()> {
+        buf.copy_from_slice(self.subseq(offset, buf.len())?);
+        Ok(())
+    }
+
+    /// Gets the next `num_bytes` bytes and then advances the
+    /// [`StreamingBinReader::current_offset`] past them.
+    pub fn next_n_bytes(&mut self, num_bytes: usize) -> Result<&[u8]> {
+        let offset = self.current_offset();
+        self.validate_offset(offset, num_bytes)?;
+        self.position += num_bytes;
+        let start = offset - self.initial_offset;
+        Ok(&self.buffer[start..start + num_bytes])
+    }
+
+    /// Gets the `u8` at the provided offset without altering the
+    /// [`StreamingBinReader::current_offset`].
+    pub fn u8_at(&mut self, offset: usize) -> Result<u8> {
+        Ok(self.subseq(offset, 1)?[0])
+    }
+
+    /// Gets the `i8` at the provided offset without altering the
+    /// [`StreamingBinReader::current_offset`].
+    pub fn i8_at(&mut self, offset: usize) -> Result<i8> {
+        Ok(self.u8_at(offset)? as i8)
+    }
+
+    /// Gets the next `u8` and then advances the [`StreamingBinReader::current_offset`] by `1`.
+    pub fn next_u8(&mut self) -> Result<u8> {
+        Ok(self.next_n_bytes(1)?[0])
+    }
+
+    /// Gets the next `i8` and then advances the [`StreamingBinReader::current_offset`] by `1`.
+    pub fn next_i8(&mut self) -> Result<i8> {
+        Ok(self.next_u8()? as i8)
+    }
+
+    make_number_methods! {
+        /// Gets the numendlong endian `numname` at the provided offset without altering the
+        /// [`StreamingBinReader::current_offset`].
+        pub fn numname_numend_at(&mut self, offset: usize) -> Result<_numname_> {
+            let mut buf = [0; _numwidth_];
+            self.bytes_at(offset, &mut buf)?;
+            Ok(_numname_::from_numend_bytes(buf))
+        }
+    }
+
+    make_number_methods! {
+        /// Gets the next numendlong endian `numname` and then advances the
+        /// [`StreamingBinReader::current_offset`] past it.
+        pub fn next_numname_numend(&mut self) -> Result<_numname_> {
+            let mut buf = [0; _numwidth_];
+            buf.copy_from_slice(self.next_n_bytes(_numwidth_)?);
+            Ok(_numname_::from_numend_bytes(buf))
+        }
+    }
+
+    default_endidness_methods! {
+        &mut StreamingBinReader;
+        u16: next_u16, next_u16_be, next_u16_le;
+        u32: next_u32, next_u32_be, next_u32_le;
+        u64: next_u64, next_u64_be, next_u64_le;
+        u128: next_u128, next_u128_be, next_u128_le;
+        i16: next_i16, next_i16_be, next_i16_le;
+        i32: next_i32, next_i32_be, next_i32_le;
+        i64: next_i64, next_i64_be, next_i64_le;
+        i128: next_i128, next_i128_be, next_i128_le;
+    }
+
+    /// Reads everything that's left in the underlying reader, then converts this into a
+    /// [`RandomAccessBinReader`] with the same initial offset, endidness and
+    /// [`StreamingBinReader::current_offset`]. The buffered data isn't copied.
+    pub fn into_random_access(mut self) -> Result<RandomAccessBinReader> {
+        self.fill_to(usize::MAX)?;
+        let reader = RandomAccessBinReader::from_bytes_with_offset(
+            self.buffer.freeze(),
+            self.initial_offset,
+            self.endidness,
+        )?;
+        crate::BinReader::advance_by(&reader, self.position as isize)?;
+        Ok(reader)
+    }
+
+    /// Returns the underlying reader. Any data that has been buffered is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testing::TEST_DATA, BinReader};
+    use std::io::Read as _;
+
+    /// Wraps a reader, keeping track of how many bytes have been pulled from it.
+    struct CountingReader<'a> {
+        inner: &'a [u8],
+        read: usize,
+    }
+
+    impl io::Read for CountingReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let read = self.inner.read(buf)?;
+            self.read += read;
+            Ok(read)
+        }
+    }
+
+    #[test]
+    fn streaming_reader_test() {
+        let source = CountingReader {
+            inner: &TEST_DATA,
+            read: 0,
+        };
+        let mut reader = StreamingBinReader::with_offset(source, 10, Endidness::Big);
+        assert_eq!(reader.upper_offset_limit(), 10);
+        assert_eq!(reader.size(), None);
+        assert_eq!(
+            reader.next_u16().unwrap(),
+            u16::from_be_bytes([TEST_DATA[0], TEST_DATA[1]])
+        );
+        assert_eq!(reader.buffered(), 2);
+        assert_eq!(reader.u32_le_at(14).unwrap(), 0x07060504);
+        assert_eq!(reader.upper_offset_limit(), 18);
+        assert_eq!(reader.current_offset(), 12);
+        assert_eq!(reader.next_n_bytes(3).unwrap(), &TEST_DATA[2..5]);
+        assert_eq!(reader.u8_at(10).unwrap(), TEST_DATA[0]);
+        assert!(matches!(reader.u8_at(9), Err(Error::OffsetTooSmall(9))));
+        assert_eq!(reader.into_inner().read, 8);
+    }
+
+    #[test]
+    fn streaming_reader_eof_test() {
+        let source = (&TEST_DATA[..3]).chain(&TEST_DATA[3..]);
+        let mut reader = StreamingBinReader::new(source, Endidness::Unknown);
+        assert!(matches!(reader.next_u32(), Err(Error::UnknownEndidness)));
+        reader.change_endidness(Endidness::Little);
+        assert_eq!(reader.next_u32().unwrap(), 0x03020100);
+        assert!(matches!(
+            reader.subseq(10, 10),
+            Err(Error::NotEnoughData(10, 6))
+        ));
+        assert_eq!(reader.size(), Some(16));
+        assert!(matches!(
+            reader.subseq(0, usize::MAX),
+            Err(Error::NotEnoughData(usize::MAX, 16))
+        ));
+        assert_eq!(reader.current_offset(), 4);
+        assert!(matches!(
+            reader.advance_to(17),
+            Err(Error::OffsetTooLarge(17))
+        ));
+        reader.advance_to(16).unwrap();
+        assert!(matches!(reader.next_u8(), Err(Error::NoMoreData)));
+        reader.advance_by(-2).unwrap();
+        assert_eq!(reader.next_i16_be().unwrap(), 0x0e0f);
+    }
+
+    #[test]
+    fn into_random_access_test() {
+        let mut reader = StreamingBinReader::with_offset(&TEST_DATA[..], 5, Endidness::Big);
+        reader.advance_by(3).unwrap();
+        assert_eq!(reader.buffered(), 3);
+        let reader = reader.into_random_access().unwrap();
+        assert_eq!(reader.as_ref(), &TEST_DATA);
+        assert_eq!(reader.initial_offset(), 5);
+        assert_eq!(reader.current_offset(), 8);
+        assert_eq!(reader.endidness(), Endidness::Big);
+    }
+}
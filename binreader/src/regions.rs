@@ -0,0 +1,31 @@
+use std::{cell::RefCell, ops::Range};
+
+type Region = (String, Range<usize>);
+
+/// Keeps track of labeled regions of a reader's data. Tracking is opt-in, so until
+/// [`RegionTracker::enable`] is called, labels are discarded.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct RegionTracker {
+    regions: RefCell<Option<Vec<Region>>>,
+}
+
+impl RegionTracker {
+    pub(crate) fn enable(&self) {
+        let mut regions = self.regions.borrow_mut();
+        if regions.is_none() {
+            regions.replace(Vec::new());
+        }
+    }
+
+    pub(crate) fn label(&self, name: &str, start: usize, end: usize) {
+        if let Some(regions) = self.regions.borrow_mut().as_mut() {
+            regions.push((name.to_string(), start..end));
+        }
+    }
+
+    pub(crate) fn regions(&self) -> Vec<Region> {
+        let mut regions = self.regions.borrow().clone().unwrap_or_default();
+        regions.sort_by_key(|(_, range)| range.start);
+        regions
+    }
+}
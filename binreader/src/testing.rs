@@ -1,4 +1,4 @@
-use super::{BinReader, Endidness};
+use super::{BinReader, Endidness, Error};
 
 pub(crate) const TEST_DATA: [u8; 16] = [
     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
@@ -51,6 +51,37 @@ pub(crate) fn basic_test_1<'r, B: BinReader<'r>>() {
     }
 }
 
+pub(crate) fn next_bytes_are_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Unknown).unwrap();
+    assert!(reader.next_bytes_are(&[0x00, 0x01]).unwrap());
+    assert!(!reader.next_bytes_are(&[0x00, 0x02]).unwrap());
+    assert!(!reader.next_bytes_are(&[0x01]).unwrap());
+    assert!(reader.next_bytes_are(&[]).unwrap());
+    assert_eq!(reader.current_offset(), 0);
+    reader.advance_to(14).unwrap();
+    assert!(reader.next_bytes_are(&[0x0e, 0x0f]).unwrap());
+    assert!(matches!(
+        reader.next_bytes_are(&[0x0e, 0x0f, 0x10]),
+        Err(Error::NotEnoughData(3, 2))
+    ));
+}
+
+pub(crate) fn u8_at_after_reads_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 3, Endidness::Unknown).unwrap();
+    assert_eq!(reader.u8_at(5).unwrap(), 0x02);
+    for _ in 0..6 {
+        reader.next_u8().unwrap();
+    }
+    assert_eq!(reader.current_offset(), 9);
+    assert_eq!(reader.u8_at(3).unwrap(), 0x00);
+    assert_eq!(reader.u8_at(5).unwrap(), 0x02);
+    assert_eq!(reader.u8_at(9).unwrap(), 0x06);
+    assert_eq!(reader.u8_at(18).unwrap(), 0x0f);
+    assert!(matches!(reader.u8_at(2), Err(Error::OffsetTooSmall(2))));
+    assert!(matches!(reader.u8_at(19), Err(Error::NoMoreData)));
+    assert_eq!(reader.current_offset(), 9);
+}
+
 pub(crate) fn test_advance_by<'r, B: BinReader<'r>>() {
     let reader = B::from_slice(&TEST_DATA, Endidness::Unknown).unwrap();
     for i in 0..TEST_DATA.len() {
@@ -91,6 +122,120 @@ pub(crate) fn test_advance_to<'r, B: BinReader<'r>>() {
     }
 }
 
+pub(crate) fn advance_from_end_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 10, Endidness::Big).unwrap();
+    reader.advance_to_end().unwrap();
+    assert_eq!(reader.current_offset(), 26);
+    assert!(reader.is_empty());
+    reader.advance_from_end(4).unwrap();
+    assert_eq!(reader.current_offset(), 22);
+    reader.advance_from_end(16).unwrap();
+    assert_eq!(reader.current_offset(), 10);
+    assert!(matches!(
+        reader.advance_from_end(17),
+        Err(Error::OffsetTooSmall(9))
+    ));
+    assert!(matches!(
+        reader.advance_from_end(27),
+        Err(Error::OffsetTooSmall(0))
+    ));
+    assert_eq!(reader.current_offset(), 10);
+}
+
+pub(crate) fn align_to_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    reader.skip(3).unwrap();
+    assert_eq!(reader.current_offset(), 3);
+    reader.align_to(4).unwrap();
+    assert_eq!(reader.current_offset(), 4);
+    reader.align_to(4).unwrap();
+    assert_eq!(reader.current_offset(), 4);
+    reader.skip(9).unwrap();
+    assert!(matches!(
+        reader.skip(usize::MAX),
+        Err(Error::NotEnoughData(usize::MAX, 3))
+    ));
+    assert_eq!(reader.current_offset(), 13);
+    reader.align_to(8).unwrap();
+    assert_eq!(reader.current_offset(), 16);
+    reader.advance_to(15).unwrap();
+    assert!(matches!(
+        reader.align_to(32),
+        Err(Error::OffsetTooLarge(32))
+    ));
+    assert_eq!(reader.current_offset(), 15);
+    assert!(matches!(reader.align_to(0), Err(Error::Other(_))));
+    let reader = B::from_slice_with_offset(&TEST_DATA, 5, Endidness::Big).unwrap();
+    reader.skip(1).unwrap();
+    reader.align_to(4).unwrap();
+    assert_eq!(reader.current_offset(), 9);
+}
+
+pub(crate) fn read_aligned_field_u32_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 3, Endidness::Big).unwrap();
+    assert_eq!(reader.next_u16().unwrap(), 0x0001);
+    assert_eq!(reader.read_aligned_field_u32(4).unwrap(), 0x04050607);
+    assert_eq!(reader.current_offset(), 11);
+    assert_eq!(reader.read_aligned_field_u32(4).unwrap(), 0x08090a0b);
+    assert_eq!(reader.current_offset(), 15);
+    reader.advance_by(1).unwrap();
+    assert!(matches!(
+        reader.read_aligned_field_u32(4),
+        Err(Error::NoMoreData)
+    ));
+    assert_eq!(reader.current_offset(), 16);
+    assert!(matches!(
+        reader.read_aligned_field_u32(2),
+        Err(Error::NoMoreData)
+    ));
+    assert_eq!(reader.current_offset(), 16);
+    assert!(matches!(
+        reader.read_aligned_field_u32(0),
+        Err(Error::Other(_))
+    ));
+    assert_eq!(reader.current_offset(), 16);
+}
+
+pub(crate) fn bookmark_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 10, Endidness::Big).unwrap();
+    reader.advance_by(2).unwrap();
+    let bookmark = reader.bookmark();
+    assert_eq!(bookmark.offset(), 12);
+    reader.advance_to(20).unwrap();
+    reader.restore(bookmark).unwrap();
+    assert_eq!(reader.current_offset(), 12);
+    let value = reader
+        .with_saved_offset(|reader| {
+            reader.advance_to(24)?;
+            reader.next_u8()
+        })
+        .unwrap();
+    assert_eq!(value, TEST_DATA[14]);
+    assert_eq!(reader.current_offset(), 12);
+    assert!(matches!(
+        reader.with_saved_offset(|reader| {
+            reader.advance_to(25)?;
+            reader.u32_at(25)
+        }),
+        Err(Error::NotEnoughData(4, 1))
+    ));
+    assert_eq!(reader.current_offset(), 12);
+}
+
+pub(crate) fn clone_test<'r, B: BinReader<'r> + Clone>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 10, Endidness::Big).unwrap();
+    reader.advance_by(4).unwrap();
+    let fork = reader.clone();
+    assert_eq!(fork.current_offset(), 14);
+    assert_eq!(fork.initial_offset(), 10);
+    assert_eq!(fork.endidness(), Endidness::Big);
+    assert_eq!(fork.next_u32().unwrap(), reader.u32_at(14).unwrap());
+    assert_eq!(fork.current_offset(), 18);
+    assert_eq!(reader.current_offset(), 14);
+    reader.advance_by(1).unwrap();
+    assert_eq!(fork.current_offset(), 18);
+}
+
 pub(crate) fn next_n_bytes_test<'r, B: BinReader<'r>>() {
     let reader = B::from_slice_with_offset(&TEST_DATA, 0, Endidness::Unknown).unwrap();
     let slice1 = reader.next_n_bytes(5).unwrap();
@@ -99,6 +244,88 @@ pub(crate) fn next_n_bytes_test<'r, B: BinReader<'r>>() {
     let slice2 = reader.next_n_bytes(5).unwrap();
     assert_eq!(slice2, &TEST_DATA[5..10]);
     assert_eq!(reader.get_remaining().unwrap(), &TEST_DATA[10..]);
+    let reader = B::from_slice_with_offset(&TEST_DATA, 7, Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_n_bytes(5).unwrap(), &TEST_DATA[..5]);
+    assert_eq!(reader.next_n_bytes(5).unwrap(), &TEST_DATA[5..10]);
+    assert_eq!(reader.current_offset(), 17);
+    assert!(matches!(
+        reader.next_n_bytes(7),
+        Err(Error::NotEnoughData(7, 6))
+    ));
+    assert_eq!(reader.next_n_bytes(6).unwrap(), &TEST_DATA[10..]);
+}
+
+pub(crate) fn validate_offset_large_size_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.validate_offset(0, 1000),
+        Err(Error::NotEnoughData(1000, 16))
+    ));
+    assert!(matches!(
+        reader.next_n_bytes(1000),
+        Err(Error::NotEnoughData(1000, 16))
+    ));
+    assert!(matches!(
+        reader.validate_offset(10, usize::MAX),
+        Err(Error::NotEnoughData(usize::MAX, 6))
+    ));
+    assert!(matches!(
+        reader.subseq(usize::MAX, usize::MAX),
+        Err(Error::OffsetTooLarge(usize::MAX))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+}
+
+pub(crate) fn iter_bytes_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 10, Endidness::Little).unwrap();
+    let bytes = reader.iter_bytes().collect::<Vec<_>>();
+    assert_eq!(bytes, TEST_DATA);
+    reader.advance_by(12).unwrap();
+    let mut iter = reader.iter_bytes();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.endidness(), Endidness::Little);
+    assert_eq!(iter.next(), Some(TEST_DATA[12]));
+    let mut count = 0;
+    for (byte, expected) in iter.zip(&TEST_DATA[13..]) {
+        assert_eq!(byte, *expected);
+        count += 1;
+    }
+    assert_eq!(count, 3);
+    assert_eq!(reader.current_offset(), 22);
+    reader.advance_to_end().unwrap();
+    assert_eq!(reader.iter_bytes().next(), None);
+}
+
+pub(crate) fn iter_numbers_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&[1, 0, 2, 0, 3, 0, 0, 0, 9], Endidness::Little).unwrap();
+    let values = reader
+        .iter_u16(None)
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(values, vec![1, 2, 3, 0]);
+    assert_eq!(reader.current_offset(), 8);
+    reader.advance_to(0).unwrap();
+    let values = reader
+        .iter_u16(Some(Endidness::Big))
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(values, vec![0x100, 0x200, 0x300, 0]);
+    reader.advance_to(0).unwrap();
+    let values = reader
+        .iter_u32(None)
+        .collect::<crate::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(values, vec![0x0002_0001, 3]);
+    assert_eq!(reader.current_offset(), 8);
+    reader.advance_to(1).unwrap();
+    let mut iter = reader.iter_u64(None);
+    assert_eq!(iter.next().unwrap().unwrap(), 0x0900_0000_0300_0200);
+    assert!(iter.next().is_none());
+    let reader = B::from_slice(&TEST_DATA, Endidness::Unknown).unwrap();
+    let mut iter = reader.iter_u32(None);
+    assert!(matches!(iter.next(), Some(Err(Error::UnknownEndidness))));
+    assert!(iter.next().is_none());
+    assert_eq!(reader.current_offset(), 0);
 }
 
 pub(crate) fn basic_le_test<'r, B: BinReader<'r>>() {
@@ -135,6 +362,32 @@ pub(crate) fn basic_be_test<'r, B: BinReader<'r>>() {
     assert_eq!(BE_U128_DATA, reader.next_u128().unwrap());
 }
 
+pub(crate) fn basic_ne_test<'r, B: BinReader<'r>>() {
+    use std::convert::TryInto;
+    let reader = B::from_slice(&TEST_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(
+        reader.u32_ne_at(1).unwrap(),
+        u32::from_ne_bytes([TEST_DATA[1], TEST_DATA[2], TEST_DATA[3], TEST_DATA[4]])
+    );
+    assert_eq!(
+        reader.current_u16_ne().unwrap(),
+        u16::from_ne_bytes([TEST_DATA[0], TEST_DATA[1]])
+    );
+    assert_eq!(
+        reader.next_i16_ne().unwrap(),
+        i16::from_ne_bytes([TEST_DATA[0], TEST_DATA[1]])
+    );
+    assert_eq!(
+        reader.next_u64_ne().unwrap(),
+        u64::from_ne_bytes(TEST_DATA[2..10].try_into().unwrap())
+    );
+    assert_eq!(reader.current_offset(), 10);
+    assert!(matches!(
+        reader.u128_ne_at(1),
+        Err(Error::NotEnoughData(16, 15))
+    ));
+}
+
 pub(crate) fn test_sliced_retain_offset<'r, B: BinReader<'r>>() {
     let base_reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
     base_reader.advance_to(0x03).unwrap();
@@ -151,3 +404,1868 @@ pub(crate) fn test_sliced_retain_offset<'r, B: BinReader<'r>>() {
         base_reader.current_offset() + sliced_reader.size()
     );
 }
+
+#[cfg(feature = "snappy")]
+pub(crate) fn snappy_test<'r, B: BinReader<'r>>() {
+    use crate::SnappyFormat;
+    use std::io::Write as _;
+    let raw = snap::raw::Encoder::new().compress_vec(&TEST_DATA).unwrap();
+    let mut framed = Vec::new();
+    snap::write::FrameEncoder::new(&mut framed)
+        .write_all(&TEST_DATA)
+        .unwrap();
+    let data = [raw.as_slice(), framed.as_slice()].concat().leak();
+    let reader = B::from_slice(data, Endidness::Big).unwrap();
+    let decompressed = reader.snappy_next_n(raw.len(), SnappyFormat::Raw).unwrap();
+    assert_eq!(decompressed.as_ref(), &TEST_DATA);
+    assert_eq!(reader.current_offset(), raw.len());
+    let decompressed = reader
+        .snappy_next_n(framed.len(), SnappyFormat::Framed)
+        .unwrap();
+    assert_eq!(decompressed.as_ref(), &TEST_DATA);
+    assert!(reader.is_empty());
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    assert!(reader.snappy_next_n(4, SnappyFormat::Framed).is_err());
+    assert_eq!(reader.current_offset(), 0);
+}
+
+#[cfg(feature = "lz4")]
+pub(crate) fn lz4_test<'r, B: BinReader<'r>>() {
+    use std::io::Write as _;
+    let block = lz4_flex::block::compress(&TEST_DATA);
+    let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+    encoder.write_all(&TEST_DATA).unwrap();
+    let framed = encoder.finish().unwrap();
+    let data = [block.as_slice(), framed.as_slice()].concat().leak();
+    let reader = B::from_slice(data, Endidness::Big).unwrap();
+    assert!(reader.lz4_next_n(block.len(), TEST_DATA.len() + 1).is_err());
+    assert_eq!(reader.current_offset(), 0);
+    let decompressed = reader.lz4_next_n(block.len(), TEST_DATA.len()).unwrap();
+    assert_eq!(decompressed.as_ref(), &TEST_DATA);
+    assert_eq!(reader.current_offset(), block.len());
+    let decompressed = reader.lz4_frame_next_n(framed.len()).unwrap();
+    assert_eq!(decompressed.as_ref(), &TEST_DATA);
+    assert!(reader.is_empty());
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    assert!(reader.lz4_frame_next_n(TEST_DATA.len()).is_err());
+    assert_eq!(reader.current_offset(), 0);
+}
+
+#[cfg(feature = "zlib")]
+fn zlib_compress(data: &[u8]) -> Vec<u8> {
+    use std::io::Write as _;
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[cfg(feature = "zlib")]
+pub(crate) fn zlib_test<'r, B: BinReader<'r>>() {
+    let compressed = zlib_compress(&TEST_DATA).leak();
+    let reader = B::from_slice(compressed, Endidness::Big).unwrap();
+    assert!(reader.zlib_next_n(compressed.len() - 1).is_err());
+    assert_eq!(reader.current_offset(), 0);
+    let decompressed = reader.zlib_next_n(compressed.len()).unwrap();
+    assert_eq!(decompressed.as_ref(), &TEST_DATA);
+    assert!(reader.is_empty());
+}
+
+pub(crate) fn next_typed_compressed_block_test<'r, B: BinReader<'r>>() {
+    let mut data = vec![0, 0, 0, 0, 4, 0xde, 0xad, 0xbe, 0xef];
+    #[cfg(feature = "zlib")]
+    {
+        let compressed = zlib_compress(&TEST_DATA);
+        data.push(1);
+        data.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+        data.extend_from_slice(&compressed);
+    }
+    data.extend_from_slice(&[9, 0, 0, 0, 0]);
+    let reader = B::from_slice(data.leak(), Endidness::Big).unwrap();
+    let block = reader.next_typed_compressed_block().unwrap();
+    assert_eq!(block.as_ref(), &[0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(block.endidness(), Endidness::Big);
+    assert_eq!(reader.current_offset(), 9);
+    #[cfg(feature = "zlib")]
+    {
+        let block = reader.next_typed_compressed_block().unwrap();
+        assert_eq!(block.as_ref(), &TEST_DATA);
+    }
+    let offset = reader.current_offset();
+    assert!(matches!(
+        reader.next_typed_compressed_block(),
+        Err(Error::InvalidData(o)) if o == offset
+    ));
+    assert_eq!(reader.current_offset(), offset);
+    let reader = B::from_slice(&[0, 0, 0, 0, 4, 0xde, 0xad], Endidness::Big).unwrap();
+    assert!(matches!(
+        reader.next_typed_compressed_block(),
+        Err(Error::NotEnoughData(4, 2))
+    ));
+}
+
+pub(crate) fn read_hex_field_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(b"00ffBEEFg1", Endidness::Unknown).unwrap();
+    assert_eq!(reader.read_hex_field(4).unwrap(), 255);
+    assert_eq!(reader.current_offset(), 4);
+    assert_eq!(reader.read_hex_field(4).unwrap(), 0xbeef);
+    assert!(matches!(
+        reader.read_hex_field(2),
+        Err(Error::InvalidData(8))
+    ));
+    assert_eq!(reader.current_offset(), 8);
+    let reader = B::from_slice(b"1ffffffffffffffff", Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_hex_field(17),
+        Err(Error::InvalidData(16))
+    ));
+}
+
+pub(crate) fn next_hex_string_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&[0x00, 0x01, 0x02, 0x03, 0xab, 0xcd], Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_hex_string(4).unwrap(), "00010203");
+    assert_eq!(reader.next_hex_string_with(1, true).unwrap(), "AB");
+    assert_eq!(reader.next_hex_string(0).unwrap(), "");
+    assert!(matches!(
+        reader.next_hex_string(2),
+        Err(Error::NotEnoughData(2, 1))
+    ));
+    assert_eq!(reader.current_offset(), 5);
+}
+
+pub(crate) fn sync_to_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 4, Endidness::Unknown).unwrap();
+    assert_eq!(reader.sync_to(&[0x06, 0x07], true).unwrap(), 6);
+    assert_eq!(reader.current_offset(), 10);
+    assert_eq!(reader.next_u8().unwrap(), 0x06);
+    assert_eq!(reader.sync_to(&[0x07, 0x08], true).unwrap(), 0);
+    assert_eq!(reader.current_offset(), 11);
+    assert!(matches!(
+        reader.sync_to(&[0x0f, 0x00], true),
+        Err(Error::NoMoreData)
+    ));
+    assert!(reader.is_empty());
+    let reader = B::from_slice(&TEST_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(
+        reader.sync_to(&[0xff, 0xff], false).unwrap(),
+        TEST_DATA.len()
+    );
+    assert!(reader.is_empty());
+}
+
+pub(crate) fn find_bytes_test<'r, B: BinReader<'r>>() {
+    let reader =
+        B::from_slice_with_offset(b"\xff\xd8\xff\xe0..\xff\xdb..\xff\xdb", 8, Endidness::Big)
+            .unwrap();
+    assert_eq!(reader.find_bytes(b"\xff\xdb").unwrap(), Some(14));
+    assert_eq!(reader.find_bytes(b"\xff\xc0").unwrap(), None);
+    assert_eq!(reader.current_offset(), 8);
+    reader.seek_to_bytes(b"\xff\xdb").unwrap();
+    assert_eq!(reader.current_offset(), 14);
+    reader.seek_to_bytes(b"\xff\xdb").unwrap();
+    assert_eq!(reader.current_offset(), 14);
+    reader.advance_by(1).unwrap();
+    reader.seek_to_bytes(b"\xff\xdb").unwrap();
+    assert_eq!(reader.current_offset(), 18);
+    reader.advance_by(1).unwrap();
+    assert!(matches!(
+        reader.seek_to_bytes(b"\xff\xdb"),
+        Err(Error::NoMoreData)
+    ));
+    assert_eq!(reader.current_offset(), 19);
+    assert!(matches!(reader.find_bytes(b""), Err(Error::Other(_))));
+}
+
+const IPV4_HEADER: [u8; 20] = [
+    0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0xb8, 0x61, 0xc0, 0xa8, 0x00, 0x01,
+    0xc0, 0xa8, 0x00, 0xc7,
+];
+const IPV4_HEADER_NO_CHECKSUM: [u8; 20] = [
+    0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00, 0xc0, 0xa8, 0x00, 0x01,
+    0xc0, 0xa8, 0x00, 0xc7,
+];
+
+pub(crate) fn inet_checksum_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&IPV4_HEADER_NO_CHECKSUM, 2, Endidness::Big).unwrap();
+    assert_eq!(reader.inet_checksum(2, 22).unwrap(), 0xb861);
+    let reader = B::from_slice(&IPV4_HEADER, Endidness::Big).unwrap();
+    assert_eq!(reader.inet_checksum(0, 20).unwrap(), 0);
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    assert_eq!(reader.inet_checksum(0, 3).unwrap(), !0x0201);
+    assert_eq!(reader.current_offset(), 0);
+}
+
+const NESTED_BLOCK_DATA: [u8; 11] = [
+    0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x02, 0xaa, 0xbb, 0xff,
+];
+
+pub(crate) fn next_sized_reader_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&NESTED_BLOCK_DATA, Endidness::Big).unwrap();
+    let block = reader.next_sized_reader_u32().unwrap();
+    assert_eq!(reader.current_offset(), 10);
+    assert_eq!(block.size(), 6);
+    assert_eq!(block.initial_offset(), 4);
+    assert_eq!(block.upper_offset_limit(), block.initial_offset() + 6);
+    let nested = block.next_sized_reader_u32().unwrap();
+    assert_eq!(nested.get_remaining().unwrap(), &[0xaa, 0xbb]);
+    assert_eq!(nested.initial_offset(), 8);
+    assert!(block.is_empty());
+    assert!(reader.next_sized_reader_u32().is_err());
+    assert_eq!(reader.next_u8().unwrap(), 0xff);
+}
+
+pub(crate) fn skip_sized_u32_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(
+        &[0, 0, 0, 3, 0xaa, 0xbb, 0xcc, 0, 0, 0, 0, 0, 0, 0, 9, 0xdd],
+        8,
+        Endidness::Big,
+    )
+    .unwrap();
+    let range = reader.skip_sized_u32().unwrap();
+    assert_eq!(range, 12..15);
+    assert_eq!(reader.current_offset(), 15);
+    assert_eq!(
+        reader.range(range.start, range.end).unwrap(),
+        &[0xaa, 0xbb, 0xcc]
+    );
+    assert_eq!(reader.skip_sized_u32().unwrap(), 19..19);
+    assert_eq!(reader.current_offset(), 19);
+    assert!(matches!(
+        reader.skip_sized_u32(),
+        Err(Error::NotEnoughData(9, 1))
+    ));
+    assert_eq!(reader.current_offset(), 19);
+}
+
+#[cfg(feature = "scroll")]
+pub(crate) fn scroll_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 3, Endidness::Big).unwrap();
+    assert_eq!(reader.scroll_gread::<u32>().unwrap(), 0x00010203);
+    assert_eq!(reader.current_offset(), 7);
+    assert_eq!(
+        reader.scroll_gread_with::<u16, _>(scroll::LE).unwrap(),
+        0x0504
+    );
+    assert_eq!(reader.current_offset(), 9);
+    reader.advance_by(8).unwrap();
+    assert!(matches!(
+        reader.scroll_gread::<u64>(),
+        Err(Error::NotEnoughData(8, 2))
+    ));
+    assert_eq!(reader.current_offset(), 17);
+}
+
+const ULEB128_DATA: [u8; 7] = [0x02, 0xe5, 0x8e, 0x26, 0x80, 0x01, 0x80];
+const ULEB128_TOO_LONG_DATA: [u8; 10] =
+    [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f];
+
+pub(crate) fn read_uleb128_vec_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&ULEB128_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.read_uleb128_vec(3).unwrap(), vec![2, 624485, 128]);
+    assert_eq!(reader.current_offset(), 6);
+    assert!(matches!(reader.read_uleb128_vec(1), Err(Error::NoMoreData)));
+    let reader = B::from_slice(&ULEB128_TOO_LONG_DATA, Endidness::Unknown).unwrap();
+    assert!(matches!(reader.next_uleb128(), Err(Error::VarintTooLong)));
+}
+
+pub(crate) fn read_u32_while_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 2, Endidness::Big).unwrap();
+    assert_eq!(
+        reader.read_u32_while(|v| v < 0x08000000).unwrap(),
+        vec![0x00010203, 0x04050607]
+    );
+    assert_eq!(reader.current_offset(), 10);
+    assert_eq!(reader.next_u32().unwrap(), 0x08090a0b);
+    assert!(reader.read_u32_while(|_| false).unwrap().is_empty());
+    assert_eq!(reader.current_offset(), 14);
+    assert_eq!(reader.read_u32_while(|_| true).unwrap(), vec![0x0c0d0e0f]);
+    assert_eq!(reader.current_offset(), 18);
+    assert!(reader.read_u32_while(|_| true).unwrap().is_empty());
+    let reader = B::from_slice(&TEST_DATA[..6], Endidness::Little).unwrap();
+    assert!(matches!(
+        reader.read_u32_while(|_| true),
+        Err(Error::NotEnoughData(4, 2))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+}
+
+const WASM_SECTION_DATA: [u8; 17] = [
+    0x01, 0x04, 0x01, 0x60, 0x00, 0x00, 0x00, 0x85, 0x00, 0x04, b'n', b'a', b'm', b'e', 0x0a, 0x05,
+    0x01,
+];
+
+pub(crate) fn next_wasm_section_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&WASM_SECTION_DATA, 8, Endidness::Little).unwrap();
+    let (id, section) = reader.next_wasm_section().unwrap();
+    assert_eq!(id, 1);
+    assert_eq!(section.as_ref(), &[0x01, 0x60, 0x00, 0x00]);
+    assert_eq!(section.initial_offset(), 10);
+    assert_eq!(section.endidness(), Endidness::Little);
+    assert_eq!(reader.current_offset(), 14);
+    let (id, section) = reader.next_wasm_section().unwrap();
+    assert_eq!(id, 0);
+    assert_eq!(section.as_ref(), b"\x04name");
+    assert_eq!(section.initial_offset(), 17);
+    assert_eq!(reader.current_offset(), 22);
+    assert!(matches!(
+        reader.next_wasm_section(),
+        Err(Error::NotEnoughData(5, 1))
+    ));
+    assert_eq!(reader.current_offset(), 22);
+    reader.advance_to(25).unwrap();
+    assert!(matches!(reader.next_wasm_section(), Err(Error::NoMoreData)));
+}
+
+pub(crate) fn read_delta_offset_table_test<'r, B: BinReader<'r>>() {
+    let data = [&[0x04, 0x00, 0x80, 0x01, 0x7f][..], &[0; 300]]
+        .concat()
+        .leak();
+    let reader = B::from_slice(data, Endidness::Unknown).unwrap();
+    assert_eq!(
+        reader.read_delta_offset_table(3, 6).unwrap(),
+        vec![10, 10, 138]
+    );
+    assert_eq!(reader.current_offset(), 4);
+    assert!(matches!(
+        reader.read_delta_offset_table(1, 200),
+        Err(Error::OffsetTooLarge(327))
+    ));
+    assert_eq!(reader.current_offset(), 4);
+}
+
+pub(crate) fn region_tracking_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Unknown).unwrap();
+    reader.label_region("ignored", 0, 2);
+    assert!(reader.regions().is_empty());
+    reader.enable_region_tracking();
+    reader.label_region("body", 4, 16);
+    reader.label_region("header", 0, 4);
+    assert_eq!(
+        reader.regions(),
+        vec![("header".to_string(), 0..4), ("body".to_string(), 4..16)]
+    );
+}
+
+const BITPACKED_DATA: [u8; 4] = [0x88, 0xc6, 0xfa, 0xff];
+
+pub(crate) fn read_bitpacked_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&BITPACKED_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(
+        reader.read_bitpacked(3, 8).unwrap(),
+        vec![0, 1, 2, 3, 4, 5, 6, 7]
+    );
+    assert_eq!(reader.current_offset(), 3);
+    let reader = B::from_slice(&BITPACKED_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.read_bitpacked(3, 3).unwrap(), vec![0, 1, 2]);
+    assert_eq!(reader.current_offset(), 2);
+    assert_eq!(reader.read_bitpacked(16, 1).unwrap(), vec![0xfffa]);
+    assert!(reader.read_bitpacked(65, 1).is_err());
+    assert!(matches!(
+        reader.read_bitpacked(0, usize::MAX),
+        Err(Error::Other(_))
+    ));
+    assert!(matches!(reader.read_bitpacked(0, 0), Err(Error::Other(_))));
+    assert_eq!(reader.current_offset(), 4);
+    assert!(matches!(
+        reader.read_bitpacked(8, 1),
+        Err(Error::NoMoreData)
+    ));
+    let reader = B::from_slice(&BITPACKED_DATA, Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_bitpacked(1, usize::MAX),
+        Err(Error::NotEnoughData(..))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+}
+
+pub(crate) fn implied_count_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 10, Endidness::Unknown).unwrap();
+    reader.advance_by(4).unwrap();
+    assert_eq!(reader.implied_count(16, 4, false).unwrap(), 3);
+    assert!(reader.implied_count(14, 4, false).is_err());
+    assert_eq!(reader.implied_count(14, 4, true).unwrap(), 2);
+    assert!(reader.implied_count(2, 4, true).is_err());
+    assert!(reader.implied_count(16, 0, true).is_err());
+}
+
+pub(crate) fn remaining_record_count_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.remaining_record_count(4), 4);
+    assert_eq!(reader.remaining_record_count(5), 3);
+    assert_eq!(reader.remaining_record_count(17), 0);
+    assert_eq!(reader.remaining_record_count(0), 0);
+    reader.advance_by(3).unwrap();
+    assert_eq!(reader.remaining_record_count(4), 3);
+    assert_eq!(reader.remaining_record_count(13), 1);
+}
+
+const SMPTE_DATA: [u8; 8] = [0x12, 0x34, 0x56, 0x23, 0x01, 0x5a, 0x00, 0x00];
+
+pub(crate) fn next_smpte_timecode_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&SMPTE_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_smpte_timecode().unwrap(), (12, 34, 56, 23));
+    assert!(matches!(
+        reader.next_smpte_timecode(),
+        Err(Error::InvalidData(5))
+    ));
+    assert_eq!(reader.current_offset(), 4);
+}
+
+const FRONT_CODED_DATA: [u8; 20] = [
+    0x00, 0x05, b'a', b'p', b'p', b'l', b'e', 0x04, 0x01, b'y', 0x02, 0x05, b'r', b'i', b'c', b'o',
+    b't', 0x08, 0x01, b's',
+];
+
+pub(crate) fn read_front_coded_strings_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&FRONT_CODED_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(
+        reader.read_front_coded_strings(3).unwrap(),
+        vec!["apple", "apply", "apricot"]
+    );
+    assert_eq!(reader.current_offset(), 17);
+    let reader = B::from_slice(&FRONT_CODED_DATA, Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_front_coded_strings(4),
+        Err(Error::InvalidData(17))
+    ));
+}
+
+pub(crate) fn read_kv_pairs_u32_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    assert_eq!(
+        reader.read_kv_pairs_u32(2).unwrap(),
+        vec![
+            (BE_U32_DATA[0], BE_U32_DATA[1]),
+            (BE_U32_DATA[2], BE_U32_DATA[3])
+        ]
+    );
+    assert!(reader.is_empty());
+    let reader = B::from_slice(&TEST_DATA, Endidness::Little).unwrap();
+    reader.advance_by(4).unwrap();
+    assert!(matches!(
+        reader.read_kv_pairs_u32(2),
+        Err(Error::NotEnoughData(16, 12))
+    ));
+    assert_eq!(reader.current_offset(), 4);
+}
+
+pub(crate) fn read_klv_map_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(
+        b"\x04name\x00\x03bob\x05empty\x00\x00\x01k\x00\x09z",
+        Endidness::Big,
+    )
+    .unwrap();
+    assert_eq!(
+        reader.read_klv_map(2).unwrap(),
+        vec![
+            ("name".to_string(), b"bob".to_vec()),
+            ("empty".to_string(), Vec::new()),
+        ]
+    );
+    assert_eq!(reader.current_offset(), 18);
+    assert!(matches!(
+        reader.read_klv_map(1),
+        Err(Error::NotEnoughData(9, 1))
+    ));
+    assert_eq!(reader.current_offset(), 18);
+}
+
+const ZIGZAG_DELTA_DATA: [u8; 5] = [0x04, 0x03, 0x96, 0x01, 0x00];
+
+pub(crate) fn read_zigzag_delta_i32_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&ZIGZAG_DELTA_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(
+        reader.read_zigzag_delta_i32(4, 10).unwrap(),
+        vec![12, 10, 85, 85]
+    );
+    assert!(reader.is_empty());
+    let reader = B::from_slice(&ZIGZAG_DELTA_DATA, Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_zigzag_delta_i32(1, i32::MAX),
+        Err(Error::InvalidData(0))
+    ));
+}
+
+pub(crate) fn out_of_bounds_test<'r, B: BinReader<'r> + std::io::Seek>() {
+    use std::io::SeekFrom;
+    assert!(matches!(
+        B::from_slice_with_offset(&TEST_DATA, usize::MAX - 4, Endidness::Big),
+        Err(Error::OffsetTooLarge(_))
+    ));
+    let mut reader = B::from_slice_with_offset(&TEST_DATA, 4, Endidness::Big).unwrap();
+    assert!(matches!(reader.u8_at(20), Err(Error::NoMoreData)));
+    assert!(matches!(reader.u8_at(21), Err(Error::OffsetTooLarge(21))));
+    assert!(matches!(
+        reader.subseq(8, usize::MAX),
+        Err(Error::NotEnoughData(usize::MAX, 12))
+    ));
+    assert!(matches!(reader.range(8, 6), Err(Error::OffsetTooSmall(6))));
+    assert!(reader.advance_by(isize::MAX).is_err());
+    assert!(reader.advance_by(isize::MIN).is_err());
+    assert!(reader.advance_by(-5).is_err());
+    assert_eq!(reader.current_offset(), 4);
+    reader.advance_to(10).unwrap();
+    assert!(matches!(
+        reader.relative_offset(8),
+        Err(Error::OffsetTooSmall(8))
+    ));
+    assert!(reader.seek(SeekFrom::Current(i64::MIN)).is_err());
+    assert!(reader.seek(SeekFrom::Start(u64::MAX)).is_err());
+    assert_eq!(reader.seek(SeekFrom::End(-4)).unwrap(), 16);
+    assert_eq!(reader.current_offset(), 16);
+    reader.advance_to(20).unwrap();
+    assert!(matches!(reader.next_u8(), Err(Error::NoMoreData)));
+    assert_eq!(reader.u8_at(5).unwrap(), 0x01);
+}
+
+const VARINT_STRING_DATA: [u8; 10] = [0x05, b'h', b'e', b'l', b'l', b'o', 0x00, 0x02, 0xc3, 0x28];
+
+pub(crate) fn next_varint_string_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&VARINT_STRING_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_varint_string().unwrap(), "hello");
+    assert_eq!(reader.next_varint_string().unwrap(), "");
+    assert_eq!(reader.current_offset(), 7);
+    assert!(matches!(reader.next_varint_string(), Err(Error::Other(_))));
+    assert_eq!(reader.current_offset(), 8);
+}
+
+const ZIP_HEADER_DATA: [u8; 10] = [b'P', b'K', 0x03, 0x04, 0x14, 0x00, 0x00, 0x08, 0x00, 0x00];
+
+pub(crate) fn check_constraints_test<'r, B: BinReader<'r>>() {
+    use crate::Constraint;
+    let reader = B::from_slice(&ZIP_HEADER_DATA, Endidness::Unknown).unwrap();
+    reader
+        .check(&[
+            Constraint::magic(0, b"PK"),
+            Constraint::u8_eq(2, 3),
+            Constraint::u16_eq(4, 20, Endidness::Little),
+            Constraint::u16_in_range(6, 0x0700, 0x0900, Endidness::Little),
+            Constraint::u32_aligned(6, 0x800, Endidness::Little),
+        ])
+        .unwrap();
+    let failing = Constraint::u16_eq(4, 20, Endidness::Big);
+    match reader.check(&[Constraint::magic(0, b"PK"), failing.clone()]) {
+        Err(Error::ConstraintViolated(constraint)) => assert_eq!(constraint, failing),
+        other => panic!("Expected a violated constraint, got {:?}", other),
+    }
+    assert!(matches!(
+        reader.check(&[Constraint::u32_eq(8, 0, Endidness::Big)]),
+        Err(Error::NotEnoughData(4, 2))
+    ));
+    for width in [0, 9] {
+        for endidness in [Endidness::Big, Endidness::Little] {
+            let constraint = Constraint::Equals {
+                offset: 0,
+                width,
+                endidness,
+                value: 0,
+            };
+            assert!(matches!(reader.check(&[constraint]), Err(Error::Other(_))));
+        }
+    }
+}
+
+const ODD_WIDTH_INT_DATA: [u8; 12] = [
+    0xaa, 0xff, 0xff, 0xfe, 0x12, 0x34, 0x56, 0x80, 0x00, 0x00, 0x00, 0x01,
+];
+
+pub(crate) fn int_at_with_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&ODD_WIDTH_INT_DATA, 10, Endidness::Unknown).unwrap();
+    assert_eq!(reader.int_at_with(11, 3, Endidness::Big).unwrap(), -2);
+    assert_eq!(
+        reader.int_at_with(11, 3, Endidness::Little).unwrap(),
+        -0x01_0001
+    );
+    assert_eq!(reader.int_at_with(14, 3, Endidness::Big).unwrap(), 0x123456);
+    assert_eq!(
+        reader.int_at_with(14, 3, Endidness::Little).unwrap(),
+        0x563412
+    );
+    assert_eq!(
+        reader.int_at_with(17, 5, Endidness::Big).unwrap(),
+        -0x7f_ffff_ffff
+    );
+    assert_eq!(
+        reader.int_at_with(17, 5, Endidness::Little).unwrap(),
+        0x01_0000_0080
+    );
+    assert_eq!(
+        reader.int_at_with(10, 1, Endidness::Unknown).unwrap(),
+        -0x56
+    );
+    assert!(matches!(
+        reader.int_at_with(11, 3, Endidness::Unknown),
+        Err(Error::UnknownEndidness)
+    ));
+    assert!(matches!(
+        reader.int_at_with(11, 9, Endidness::Big),
+        Err(Error::Other(_))
+    ));
+    assert!(matches!(
+        reader.int_at_with(20, 3, Endidness::Big),
+        Err(Error::NotEnoughData(3, 2))
+    ));
+}
+
+pub(crate) fn read_capped_array_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    assert!(matches!(
+        reader.read_capped_array(1_000_000, 16, |r| r.next_u8()),
+        Err(Error::Other(_))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+    assert_eq!(
+        reader.read_capped_array(3, 16, |r| r.next_u16()).unwrap(),
+        vec![0x0001, 0x0203, 0x0405]
+    );
+    assert_eq!(reader.current_offset(), 6);
+    assert!(matches!(
+        reader.read_capped_array(usize::MAX, usize::MAX, |r| r.next_u8()),
+        Err(Error::NoMoreData)
+    ));
+}
+
+const PRESENCE_BITMAP_DATA: [u8; 3] = [0b1001_0000, 0b0000_0000, 0xff];
+
+pub(crate) fn read_presence_bitmap_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&PRESENCE_BITMAP_DATA, Endidness::Big).unwrap();
+    let present = reader.read_presence_bitmap(10).unwrap();
+    assert_eq!(
+        present,
+        vec![true, false, false, true, false, false, false, false, false, false]
+    );
+    assert_eq!(reader.current_offset(), 2);
+    assert!(matches!(
+        reader.read_presence_bitmap(16),
+        Err(Error::NotEnoughData(2, 1))
+    ));
+    assert_eq!(reader.current_offset(), 2);
+}
+
+pub(crate) fn flag_at_test<'r, B: BinReader<'r>>() {
+    use crate::BitOrder;
+    let reader = B::from_slice_with_offset(&[0x00, 0b1010_0001], 4, Endidness::Unknown).unwrap();
+    assert!(reader.flag_at(5, 0, BitOrder::MsbFirst).unwrap());
+    assert!(!reader.flag_at(5, 1, BitOrder::MsbFirst).unwrap());
+    assert!(reader.flag_at(5, 2, BitOrder::MsbFirst).unwrap());
+    assert!(reader.flag_at(5, 7, BitOrder::MsbFirst).unwrap());
+    assert!(reader.flag_at(5, 0, BitOrder::LsbFirst).unwrap());
+    assert!(!reader.flag_at(5, 1, BitOrder::LsbFirst).unwrap());
+    assert!(reader.flag_at(5, 5, BitOrder::LsbFirst).unwrap());
+    assert!(reader.flag_at(5, 7, BitOrder::LsbFirst).unwrap());
+    assert!(!reader.flag_at(4, 7, BitOrder::LsbFirst).unwrap());
+    assert_eq!(reader.current_offset(), 4);
+    assert!(matches!(
+        reader.flag_at(5, 8, BitOrder::MsbFirst),
+        Err(Error::Other(_))
+    ));
+    assert!(matches!(
+        reader.flag_at(7, 0, BitOrder::MsbFirst),
+        Err(Error::OffsetTooLarge(7))
+    ));
+}
+
+const CHUNK_DATA: [u8; 23] = [
+    b'I', b'H', b'D', b'R', 0x00, 0x00, 0x00, 0x03, 0x01, 0x02, 0x03, 0x00, b'I', b'D', b'A', b'T',
+    0x00, 0x00, 0x00, 0x02, 0x04, 0x05, 0xff,
+];
+
+pub(crate) fn next_chunk_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&CHUNK_DATA, Endidness::Little).unwrap();
+    let (id, chunk) = reader.next_chunk(Endidness::Big, 2).unwrap();
+    assert_eq!(id, "IHDR");
+    assert_eq!(chunk.initial_offset(), 8);
+    assert_eq!(chunk.as_ref(), &[0x01, 0x02, 0x03]);
+    assert_eq!(reader.current_offset(), 12);
+    let (id, chunk) = reader.next_chunk(Endidness::Big, 2).unwrap();
+    assert_eq!(id, "IDAT");
+    assert_eq!(chunk.as_ref(), &[0x04, 0x05]);
+    assert_eq!(reader.current_offset(), 22);
+    reader.advance_to(0).unwrap();
+    assert!(matches!(
+        reader.next_chunk(Endidness::Little, 2),
+        Err(Error::NotEnoughData(0x0300_0000, 15))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+    let (_, chunk) = reader.next_chunk(Endidness::Big, usize::MAX).unwrap();
+    assert_eq!(chunk.as_ref(), &[0x01, 0x02, 0x03]);
+    assert_eq!(reader.current_offset(), 23);
+    let reader = B::from_slice(&CHUNK_DATA[..11], Endidness::Little).unwrap();
+    let (id, chunk) = reader.next_chunk(Endidness::Big, 2).unwrap();
+    assert_eq!(id, "IHDR");
+    assert_eq!(chunk.as_ref(), &[0x01, 0x02, 0x03]);
+    assert_eq!(reader.current_offset(), 11);
+}
+
+pub(crate) fn read_to_end_u32_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    assert_eq!(
+        reader.read_to_end_u32(false).unwrap(),
+        vec![0x00010203, 0x04050607, 0x08090a0b, 0x0c0d0e0f]
+    );
+    assert_eq!(reader.remaining(), 0);
+    assert!(reader.read_to_end_u32(false).unwrap().is_empty());
+    reader.advance_to(6).unwrap();
+    assert!(matches!(
+        reader.read_to_end_u32(false),
+        Err(Error::NotEnoughData(4, 2))
+    ));
+    assert_eq!(reader.current_offset(), 6);
+    assert_eq!(
+        reader.read_to_end_u32(true).unwrap(),
+        vec![0x06070809, 0x0a0b0c0d]
+    );
+    assert_eq!(reader.current_offset(), 14);
+}
+
+pub(crate) fn next_asn1_tlv_test<'r, B: BinReader<'r>>() {
+    let mut data = vec![0x02, 0x01, 0x2a, 0x04, 0x82, 0x01, 0x01];
+    data.extend((0..257).map(|i| i as u8));
+    data.extend([0x30, 0x80, 0x04, 0x84, 0xff, 0xff]);
+    let reader = B::from_slice(data.leak(), Endidness::Unknown).unwrap();
+    let (tag, len, value) = reader.next_asn1_tlv().unwrap();
+    assert_eq!((tag, len, value), (0x02, 1, &[0x2a][..]));
+    assert_eq!(reader.current_offset(), 3);
+    let (tag, len, value) = reader.next_asn1_tlv().unwrap();
+    assert_eq!((tag, len), (0x04, 257));
+    assert_eq!(value.len(), 257);
+    assert_eq!(value[256], 0x00);
+    assert_eq!(reader.current_offset(), 264);
+    assert!(matches!(
+        reader.next_asn1_tlv(),
+        Err(Error::InvalidData(265))
+    ));
+    reader.advance_by(2).unwrap();
+    assert!(matches!(
+        reader.next_asn1_tlv(),
+        Err(Error::NotEnoughData(4, 2))
+    ));
+    assert_eq!(reader.current_offset(), 266);
+}
+
+pub(crate) fn read_validated_array_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    let below_0x400 = |value: &u16| {
+        if *value < 0x400 {
+            Ok(())
+        } else {
+            Err(Error::Other(format!("0x{:x} is too large", value)))
+        }
+    };
+    assert_eq!(
+        reader
+            .read_validated_array(2, |r| r.next_u16(), below_0x400)
+            .unwrap(),
+        vec![0x0001, 0x0203]
+    );
+    reader.advance_to(0).unwrap();
+    match reader.read_validated_array(4, |r| r.next_u16(), below_0x400) {
+        Err(Error::At(index, e)) => {
+            assert_eq!(index, 2);
+            assert!(matches!(*e, Error::Other(_)));
+        }
+        other => panic!("Expected an Error::At, got {:?}", other),
+    }
+    reader.advance_to(12).unwrap();
+    match reader.read_validated_array(3, |r| r.next_u16(), |_| Ok(())) {
+        Err(Error::At(index, e)) => {
+            assert_eq!(index, 2);
+            assert!(matches!(*e, Error::NoMoreData));
+        }
+        other => panic!("Expected an Error::At, got {:?}", other),
+    }
+}
+
+const GUID_DATA: [u8; 17] = [
+    0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+    0x01,
+];
+
+pub(crate) fn next_guid_string_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&GUID_DATA, Endidness::Big).unwrap();
+    assert_eq!(
+        reader.next_guid_string().unwrap(),
+        "00112233-4455-6677-8899-aabbccddeeff"
+    );
+    assert_eq!(reader.current_offset(), 16);
+    assert!(matches!(
+        reader.next_guid_string(),
+        Err(Error::NotEnoughData(16, 1))
+    ));
+    assert_eq!(reader.current_offset(), 16);
+}
+
+const UTF16_FIELD_DATA: [u8; 17] = [
+    0x48, 0x00, 0x69, 0x00, 0x3d, 0xd8, 0x00, 0xde, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01,
+];
+
+pub(crate) fn read_utf16_fixed_field_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&UTF16_FIELD_DATA, Endidness::Little).unwrap();
+    assert_eq!(reader.read_utf16_fixed_field(8).unwrap(), "Hi\u{1f600}");
+    assert_eq!(reader.current_offset(), 16);
+    assert!(matches!(
+        reader.read_utf16_fixed_field(1),
+        Err(Error::NotEnoughData(2, 1))
+    ));
+    let reader = B::from_slice(&UTF16_FIELD_DATA[6..], Endidness::Little).unwrap();
+    assert!(matches!(
+        reader.read_utf16_fixed_field(2),
+        Err(Error::Other(_))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+    let reader = B::from_slice(&UTF16_FIELD_DATA, Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_utf16_fixed_field(8),
+        Err(Error::UnknownEndidness)
+    ));
+}
+
+pub(crate) fn xor_checksum_test<'r, B: BinReader<'r>>() {
+    let sentence = b"GPGSA,A,3,,,,,,16,18,,22,24,,,3.6,2.1,2.2";
+    let data = [&b"$"[..], sentence, b"*", &[0x3c]].concat().leak();
+    let reader = B::from_slice_with_offset(data, 5, Endidness::Unknown).unwrap();
+    assert_eq!(reader.xor_checksum(6, 47).unwrap(), 0x3c);
+    assert!(reader.verify_xor_checksum(6..47, 48).unwrap());
+    assert!(!reader.verify_xor_checksum(6..46, 48).unwrap());
+    assert_eq!(reader.xor_checksum(5, 5).unwrap(), 0);
+    assert!(reader.verify_xor_checksum(6..47, 49).is_err());
+    assert_eq!(reader.current_offset(), 5);
+}
+
+#[cfg(feature = "crc")]
+pub(crate) fn read_crc_protected_record_test<'r, B: BinReader<'r>>() {
+    let data = [
+        &b"123456789"[..],
+        &[0xcb, 0xf4, 0x39, 0x26],
+        b"12345678:",
+        &[0xcb, 0xf4, 0x39, 0x26],
+    ]
+    .concat()
+    .leak();
+    let reader = B::from_slice(data, Endidness::Big).unwrap();
+    assert_eq!(
+        reader.read_crc_protected_record_u32(9).unwrap(),
+        b"123456789"
+    );
+    assert_eq!(reader.current_offset(), 13);
+    assert!(matches!(
+        reader.read_crc_protected_record_u32(9),
+        Err(Error::ChecksumMismatch(0xcbf43926, _))
+    ));
+    assert_eq!(reader.current_offset(), 13);
+    assert!(matches!(
+        reader.read_crc_protected_record_u32(10),
+        Err(Error::NotEnoughData(..))
+    ));
+    assert_eq!(reader.current_offset(), 13);
+}
+
+#[cfg(feature = "crc")]
+pub(crate) fn next_n_bytes_checked_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(b"123456789", Endidness::Unknown).unwrap();
+    assert_eq!(
+        reader.next_n_bytes_checked(9).unwrap(),
+        (&b"123456789"[..], 0xcbf43926)
+    );
+    assert!(reader.next_n_bytes_checked(1).is_err());
+    let reader = B::from_slice_with_offset(&TEST_DATA, 3, Endidness::Unknown).unwrap();
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(&TEST_DATA[..10]);
+    assert_eq!(
+        reader.next_n_bytes_checked(10).unwrap(),
+        (&TEST_DATA[..10], hasher.finalize())
+    );
+    assert_eq!(reader.current_offset(), 13);
+    assert_eq!(reader.next_n_bytes_checked(0).unwrap(), (&[][..], 0));
+    assert!(matches!(
+        reader.next_n_bytes_checked(7),
+        Err(Error::NotEnoughData(7, 6))
+    ));
+    assert_eq!(reader.current_offset(), 13);
+}
+
+const NESTED_FRAME_DATA: [u8; 17] = [
+    0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x03, 0x01, 0x02, 0x03, 0x00, 0x00, 0x00, 0x00, 0x04,
+    0xff,
+];
+
+pub(crate) fn enter_frame_u32_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&NESTED_FRAME_DATA, Endidness::Big).unwrap();
+    let outer = reader.enter_frame_u32().unwrap();
+    assert_eq!(reader.current_offset(), 15);
+    assert_eq!((outer.initial_offset(), outer.size()), (4, 11));
+    let inner = outer.enter_frame_u32().unwrap();
+    assert_eq!(inner.as_ref(), &[0x01, 0x02, 0x03]);
+    assert_eq!(inner.initial_offset(), 8);
+    let empty = outer.enter_frame_u32().unwrap();
+    assert!(empty.is_empty());
+    assert!(outer.is_empty());
+    assert!(matches!(
+        inner.enter_frame_u32(),
+        Err(Error::NotEnoughData(4, 3))
+    ));
+    assert!(matches!(
+        reader.enter_frame_u32(),
+        Err(Error::NotEnoughData(4, 2))
+    ));
+}
+
+const SLEB128_DATA: [u8; 17] = [
+    0x00, 0x01, 0x7f, 0x80, 0x7f, 0xe5, 0x8e, 0x26, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+    0x80,
+];
+
+pub(crate) fn next_sleb128_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&SLEB128_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_sleb128().unwrap(), 0);
+    assert_eq!(reader.next_sleb128().unwrap(), 1);
+    assert_eq!(reader.next_sleb128().unwrap(), -1);
+    assert_eq!(reader.next_sleb128().unwrap(), -128);
+    assert_eq!(reader.next_sleb128().unwrap(), 624485);
+    assert_eq!(reader.current_offset(), 8);
+    assert!(matches!(reader.next_sleb128(), Err(Error::NoMoreData)));
+    let reader = B::from_slice(
+        &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f],
+        Endidness::Unknown,
+    )
+    .unwrap();
+    assert_eq!(reader.next_sleb128().unwrap(), i64::MIN);
+    let reader = B::from_slice(
+        &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01],
+        Endidness::Unknown,
+    )
+    .unwrap();
+    assert!(matches!(reader.next_sleb128(), Err(Error::VarintTooLong)));
+}
+
+pub(crate) fn next_sleb128p1_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&SLEB128_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_sleb128p1().unwrap(), -1);
+    assert_eq!(reader.next_sleb128p1().unwrap(), 0);
+    assert_eq!(reader.next_sleb128p1().unwrap(), -2);
+    assert_eq!(reader.next_sleb128p1().unwrap(), -129);
+    assert_eq!(reader.current_offset(), 5);
+}
+
+pub(crate) fn read_rle_u8_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(
+        &[0x03, 0xaa, 0x00, 0xcc, 0x02, 0xbb, 0x01],
+        Endidness::Unknown,
+    )
+    .unwrap();
+    assert!(matches!(reader.read_rle_u8(3, 4), Err(Error::Other(_))));
+    assert_eq!(reader.current_offset(), 0);
+    assert_eq!(
+        reader.read_rle_u8(3, 5).unwrap(),
+        vec![0xaa, 0xaa, 0xaa, 0xbb, 0xbb]
+    );
+    assert_eq!(reader.current_offset(), 6);
+    assert!(matches!(
+        reader.read_rle_u8(1, usize::MAX),
+        Err(Error::NotEnoughData(2, 1))
+    ));
+    assert_eq!(reader.current_offset(), 6);
+}
+
+pub(crate) fn u32_conditional_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.u32_conditional(4, true).unwrap(), 0x04050607);
+    assert_eq!(reader.u32_conditional(4, false).unwrap(), 0x07060504);
+    assert!(matches!(
+        reader.u32_conditional(14, true),
+        Err(Error::NotEnoughData(4, 2))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+}
+
+pub(crate) fn next_u32_strided_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(
+        &[
+            1, 0, 0, 0, 0xee, 0xee, 0xee, 0xee, 2, 0, 0, 0, 0xee, 0xee, 0xee, 0xee, 3, 0, 0, 0,
+        ],
+        Endidness::Little,
+    )
+    .unwrap();
+    assert_eq!(reader.next_u32_strided(8).unwrap(), 1);
+    assert_eq!(reader.next_u32_strided(8).unwrap(), 2);
+    assert_eq!(reader.current_offset(), 16);
+    assert!(matches!(
+        reader.next_u32_strided(8),
+        Err(Error::NotEnoughData(8, 4))
+    ));
+    assert!(matches!(reader.next_u32_strided(3), Err(Error::Other(_))));
+    assert_eq!(reader.next_u32_strided(4).unwrap(), 3);
+    assert!(reader.is_empty());
+}
+
+pub(crate) fn index_chunks_test<'r, B: BinReader<'r>>() {
+    let mut data = CHUNK_DATA[..22].to_vec();
+    data.extend(b"IEND\x00\x00\x00\x00");
+    let reader = B::from_slice_with_offset(data.leak(), 100, Endidness::Unknown).unwrap();
+    reader.advance_to(105).unwrap();
+    assert_eq!(
+        reader.index_chunks(Endidness::Big, 2).unwrap(),
+        vec![
+            ("IHDR".to_string(), 108..111),
+            ("IDAT".to_string(), 120..122),
+            ("IEND".to_string(), 130..130),
+        ]
+    );
+    assert_eq!(reader.current_offset(), 105);
+    let reader = B::from_slice(&CHUNK_DATA, Endidness::Unknown).unwrap();
+    assert!(reader.index_chunks(Endidness::Big, 2).is_err());
+    assert_eq!(reader.current_offset(), 0);
+    let mut data = CHUNK_DATA[..12].to_vec();
+    data.extend(b"DATA\x00\x00\x00\x01\x07");
+    let reader = B::from_slice(data.leak(), Endidness::Unknown).unwrap();
+    assert_eq!(
+        reader.index_chunks(Endidness::Big, 2).unwrap(),
+        vec![("IHDR".to_string(), 8..11), ("DATA".to_string(), 20..21)]
+    );
+    assert_eq!(reader.current_offset(), 0);
+}
+
+const SEVEN_BIT_INT_DATA: [u8; 10] = [0x00, 0x7f, 0x80, 0x01, 0xff, 0xff, 0xff, 0xff, 0x0f, 0x80];
+
+pub(crate) fn next_7bit_encoded_int_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&SEVEN_BIT_INT_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_7bit_encoded_int().unwrap(), 0);
+    assert_eq!(reader.next_7bit_encoded_int().unwrap(), 127);
+    assert_eq!(reader.next_7bit_encoded_int().unwrap(), 128);
+    assert_eq!(reader.next_7bit_encoded_int().unwrap(), u32::MAX);
+    assert_eq!(reader.current_offset(), 9);
+    assert!(matches!(
+        reader.next_7bit_encoded_int(),
+        Err(Error::NoMoreData)
+    ));
+    assert_eq!(reader.current_offset(), 9);
+    let reader = B::from_slice(&[0xff, 0xff, 0xff, 0xff, 0x10], Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.next_7bit_encoded_int(),
+        Err(Error::VarintTooLong)
+    ));
+    assert_eq!(reader.current_offset(), 0);
+}
+
+pub(crate) fn cow_range_test<'r, B: BinReader<'r>>() {
+    use std::borrow::Cow;
+    let reader = B::from_slice_with_offset(&TEST_DATA, 4, Endidness::Unknown).unwrap();
+    match reader.cow_range(6, 9).unwrap() {
+        Cow::Borrowed(data) => assert_eq!(data, &[0x02, 0x03, 0x04]),
+        Cow::Owned(_) => panic!("Expected borrowed data"),
+    }
+    assert!(matches!(
+        reader.cow_range(18, 22),
+        Err(Error::NotEnoughData(4, 2))
+    ));
+}
+
+pub(crate) fn next_u16_enum_or_test<'r, B: BinReader<'r>>() {
+    #[derive(Debug, PartialEq)]
+    enum Kind {
+        Header,
+        Unknown(u16),
+    }
+    let map = |value| match value {
+        0x0001 => Kind::Header,
+        other => Kind::Unknown(other),
+    };
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    assert_eq!(reader.next_u16_enum_or(map).unwrap(), Kind::Header);
+    assert_eq!(reader.next_u16_enum_or(map).unwrap(), Kind::Unknown(0x0203));
+    assert_eq!(reader.current_offset(), 4);
+    reader.advance_to(15).unwrap();
+    assert!(reader.next_u16_enum_or(map).is_err());
+}
+
+pub(crate) fn read_with_tail_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    let (head, tail) = reader.read_with_tail(8, |r| r.next_u32()).unwrap();
+    assert_eq!(head, 0x00010203);
+    assert_eq!(tail, &[0x04, 0x05, 0x06, 0x07]);
+    assert_eq!(reader.current_offset(), 8);
+    assert!(matches!(
+        reader.read_with_tail(2, |r| r.next_u32()),
+        Err(Error::Other(_))
+    ));
+    assert_eq!(reader.current_offset(), 8);
+    assert!(matches!(
+        reader.read_with_tail(10, |r| r.next_u32()),
+        Err(Error::NotEnoughData(10, 8))
+    ));
+    assert_eq!(reader.current_offset(), 8);
+}
+
+pub(crate) fn read_field_column_u32_test<'r, B: BinReader<'r>>() {
+    let data = (0..25).collect::<Vec<u8>>().leak();
+    let reader = B::from_slice(data, Endidness::Little).unwrap();
+    assert_eq!(
+        reader.read_field_column_u32(8, 4, 3).unwrap(),
+        vec![0x07060504, 0x0f0e0d0c, 0x17161514]
+    );
+    assert_eq!(reader.current_offset(), 24);
+    reader.advance_to(0).unwrap();
+    assert!(matches!(
+        reader.read_field_column_u32(8, 4, 4),
+        Err(Error::NotEnoughData(32, 25))
+    ));
+    assert!(matches!(
+        reader.read_field_column_u32(8, 5, 1),
+        Err(Error::Other(_))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+}
+
+pub(crate) fn record_at_u32_array_test<'r, B: BinReader<'r>>() {
+    let data = (0..64u8)
+        .map(|i| if i % 4 == 3 { i / 4 } else { 0 })
+        .collect::<Vec<_>>()
+        .leak();
+    let reader = B::from_slice_with_offset(data, 100, Endidness::Big).unwrap();
+    reader.advance_by(5).unwrap();
+    assert_eq!(
+        reader.record_at_u32_array(104, 2, 4).unwrap(),
+        vec![9, 10, 11, 12]
+    );
+    assert_eq!(
+        reader.record_at_u32_array(100, 2, 4).unwrap(),
+        vec![8, 9, 10, 11]
+    );
+    assert_eq!(reader.current_offset(), 105);
+    assert!(matches!(
+        reader.record_at_u32_array(104, 2, 6),
+        Err(Error::NotEnoughData(24, 12))
+    ));
+    assert!(matches!(
+        reader.record_at_u32_array(100, usize::MAX, 4),
+        Err(Error::OffsetTooLarge(_))
+    ));
+}
+
+pub(crate) fn verify_declared_size_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 8, Endidness::Unknown).unwrap();
+    reader.verify_declared_size(16).unwrap();
+    match reader.verify_declared_size(20) {
+        Err(Error::Other(msg)) => {
+            assert_eq!(msg, "The data is truncated: declared 20 bytes but have 16.")
+        }
+        other => panic!("Expected an error, got {:?}", other),
+    }
+    match reader.verify_declared_size(12) {
+        Err(Error::Other(msg)) => {
+            assert_eq!(
+                msg,
+                "The data has trailing bytes: declared 12 bytes but have 16."
+            )
+        }
+        other => panic!("Expected an error, got {:?}", other),
+    }
+}
+
+pub(crate) fn next_version_triple_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&TEST_DATA, Endidness::Big).unwrap();
+    assert_eq!(
+        reader.next_version_triple_u16().unwrap(),
+        (0x0001, 0x0203, 0x0405)
+    );
+    assert_eq!(reader.next_version_triple_u8().unwrap(), (0x06, 0x07, 0x08));
+    assert_eq!(reader.current_offset(), 9);
+    reader.advance_to(12).unwrap();
+    assert!(matches!(
+        reader.next_version_triple_u16(),
+        Err(Error::NotEnoughData(6, 4))
+    ));
+    assert_eq!(reader.current_offset(), 12);
+}
+
+pub(crate) fn dispatch_u8_test<'r, B: BinReader<'r>>() {
+    #[derive(Debug, PartialEq)]
+    enum Op {
+        Nop,
+        PushU8(u8),
+        PushU16(u16),
+    }
+    let handler = |reader: &B, opcode| match opcode {
+        0x00 => Ok(Op::Nop),
+        0x01 => Ok(Op::PushU8(reader.next_u8()?)),
+        0x02 => Ok(Op::PushU16(reader.next_u16()?)),
+        _ => Err(Error::InvalidData(reader.current_offset() - 1)),
+    };
+    let reader =
+        B::from_slice(&[0x00, 0x02, 0xbe, 0xef, 0x01, 0x7f, 0x09], Endidness::Big).unwrap();
+    let mut ops = Vec::new();
+    while !reader.is_empty() {
+        match reader.dispatch_u8(handler) {
+            Ok(op) => ops.push(op),
+            Err(Error::InvalidData(offset)) => {
+                assert_eq!(offset, 6);
+                break;
+            }
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+    assert_eq!(ops, vec![Op::Nop, Op::PushU16(0xbeef), Op::PushU8(0x7f)]);
+}
+
+pub(crate) fn locator_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 0x30, Endidness::Little).unwrap();
+    reader.advance_by(10).unwrap();
+    assert_eq!(reader.locator(), "off=0x3A/0x40 le");
+    let reader = B::from_slice(&TEST_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.locator(), "off=0x0/0x10 unknown");
+}
+
+#[cfg(feature = "chrono")]
+pub(crate) fn next_days_since_epoch_test<'r, B: BinReader<'r>>() {
+    use chrono::NaiveDate;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let reader = B::from_slice(
+        &[0x00, 0x00, 0x4d, 0x84, 0x2a, 0xcd, 0x00, 0x00, 0x00, 0x01],
+        Endidness::Big,
+    )
+    .unwrap();
+    assert_eq!(
+        reader.next_days_since_epoch_u32(epoch).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 5, 1).unwrap()
+    );
+    assert_eq!(
+        reader.next_days_since_epoch_u16(epoch).unwrap(),
+        NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()
+    );
+    assert_eq!(reader.current_offset(), 6);
+    assert!(matches!(
+        reader.next_days_since_epoch_u32(NaiveDate::MAX),
+        Err(Error::InvalidData(6))
+    ));
+    assert_eq!(reader.current_offset(), 6);
+}
+
+pub(crate) fn monotonic_check_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&TEST_DATA, 4, Endidness::Big).unwrap();
+    reader.advance_to(10).unwrap();
+    reader.advance_to(6).unwrap();
+    reader.enable_monotonic_check();
+    reader.advance_to(8).unwrap();
+    reader.advance_to(8).unwrap();
+    match reader.advance_to(7) {
+        Err(Error::Other(msg)) => assert_eq!(msg, "non-monotonic advance"),
+        other => panic!("Expected an error, got {:?}", other),
+    }
+    assert_eq!(reader.current_offset(), 8);
+    reader.advance_by(2).unwrap();
+    reader.advance_by(0).unwrap();
+    match reader.advance_by(-1) {
+        Err(Error::Other(msg)) => assert_eq!(msg, "non-monotonic advance"),
+        other => panic!("Expected an error, got {:?}", other),
+    }
+    assert_eq!(reader.current_offset(), 10);
+    assert!(reader.monotonic_check_enabled());
+    assert_eq!(reader.with_saved_offset(|r| r.next_u8()).unwrap(), 6);
+    assert_eq!(reader.current_offset(), 10);
+    let bookmark = reader.bookmark();
+    reader.advance_to(18).unwrap();
+    assert!(reader.read_aligned_field_u32(4).is_err());
+    assert_eq!(reader.current_offset(), 18);
+    reader.restore(bookmark).unwrap();
+    assert_eq!(reader.current_offset(), 10);
+    assert!(reader.index_chunks(Endidness::Big, 2).is_err());
+    assert_eq!(reader.current_offset(), 10);
+    assert!(reader.monotonic_check_enabled());
+    reader.disable_monotonic_check();
+    assert!(!reader.monotonic_check_enabled());
+    reader.advance_to(7).unwrap();
+    assert_eq!(reader.current_offset(), 7);
+    reader.advance_by(-1).unwrap();
+    assert_eq!(reader.current_offset(), 6);
+}
+
+pub(crate) fn read_scaled_f64_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&[0x13, 0x88, 0xff, 0xff, 0x00, 0xfa], Endidness::Big).unwrap();
+    let values = reader.read_scaled_f64(2, 0.01, -40.0, 2, false).unwrap();
+    assert!((values[0] - 10.0).abs() < 1e-9);
+    assert!((values[1] - 615.35).abs() < 1e-9);
+    assert_eq!(reader.current_offset(), 4);
+    reader.advance_to(2).unwrap();
+    let values = reader.read_scaled_f64(2, 0.01, -40.0, 2, true).unwrap();
+    assert!((values[0] + 40.01).abs() < 1e-9);
+    assert!((values[1] + 37.5).abs() < 1e-9);
+    assert!(matches!(
+        reader.read_scaled_f64(1, 0.01, -40.0, 2, true),
+        Err(Error::NoMoreData)
+    ));
+    reader.advance_to(0).unwrap();
+    assert!(matches!(
+        reader.read_scaled_f64(1, 0.01, -40.0, 9, true),
+        Err(Error::Other(_))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+}
+
+pub(crate) fn next_sample_normalized_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(
+        &[0x00, 0x80, 0x00, 0x00, 0xff, 0x7f, 0x00, 0x00, 0x80, 0x80],
+        Endidness::Little,
+    )
+    .unwrap();
+    assert_eq!(reader.next_sample_normalized(2).unwrap(), -1.0);
+    assert_eq!(reader.next_sample_normalized(2).unwrap(), 0.0);
+    let max = reader.next_sample_normalized(2).unwrap();
+    assert!(max < 1.0 && max > 0.9999);
+    assert_eq!(reader.next_sample_normalized(3).unwrap(), -1.0);
+    assert_eq!(reader.current_offset(), 9);
+    assert!(matches!(
+        reader.next_sample_normalized(5),
+        Err(Error::Other(_))
+    ));
+    assert!(matches!(
+        reader.next_sample_normalized(2),
+        Err(Error::NotEnoughData(2, 1))
+    ));
+    assert_eq!(reader.current_offset(), 9);
+}
+
+pub(crate) fn stereo_i16_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(
+        &[0x01, 0x00, 0xff, 0xff, 0x00, 0x80, 0xff, 0x7f, 0x02, 0x00],
+        Endidness::Little,
+    )
+    .unwrap();
+    assert_eq!(reader.next_stereo_sample_i16().unwrap(), (1, -1));
+    assert_eq!(reader.current_offset(), 4);
+    reader.advance_to(0).unwrap();
+    assert_eq!(
+        reader.read_stereo_i16_vec(2).unwrap(),
+        vec![(1, -1), (i16::MIN, i16::MAX)]
+    );
+    assert_eq!(reader.current_offset(), 8);
+    assert!(matches!(
+        reader.next_stereo_sample_i16(),
+        Err(Error::NotEnoughData(4, 2))
+    ));
+    reader.advance_to(0).unwrap();
+    assert!(matches!(
+        reader.read_stereo_i16_vec(3),
+        Err(Error::NotEnoughData(12, 10))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+}
+
+pub(crate) fn packed_color_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(
+        &[
+            0xf8, 0x00, 0x07, 0xe0, 0x00, 0x1f, 0xff, 0xff, 0x00, 0x00, 0x84, 0x10, 0x7c, 0x00,
+            0x83, 0xe0, 0xf0, 0x0f, 0x12, 0x34, 0xff,
+        ],
+        Endidness::Big,
+    )
+    .unwrap();
+    assert_eq!(reader.next_rgb565().unwrap(), (255, 0, 0));
+    assert_eq!(reader.next_rgb565().unwrap(), (0, 255, 0));
+    assert_eq!(reader.next_rgb565().unwrap(), (0, 0, 255));
+    assert_eq!(reader.next_rgb565().unwrap(), (255, 255, 255));
+    assert_eq!(reader.next_rgb565().unwrap(), (0, 0, 0));
+    assert_eq!(reader.next_rgb565().unwrap(), (132, 130, 132));
+    assert_eq!(reader.next_rgb555().unwrap(), (255, 0, 0));
+    assert_eq!(reader.next_rgb555().unwrap(), (0, 255, 0));
+    assert_eq!(reader.next_rgba4444().unwrap(), (255, 0, 0, 255));
+    assert_eq!(reader.next_rgba4444().unwrap(), (0x11, 0x22, 0x33, 0x44));
+    assert_eq!(reader.current_offset(), 20);
+    assert!(matches!(
+        reader.next_rgb565(),
+        Err(Error::NotEnoughData(2, 1))
+    ));
+    assert_eq!(reader.current_offset(), 20);
+    let reader = B::from_slice(&[0x1f, 0x00], Endidness::Little).unwrap();
+    assert_eq!(reader.next_rgb565().unwrap(), (0, 0, 255));
+    let reader = B::from_slice(&[0x1f, 0x00], Endidness::Unknown).unwrap();
+    assert!(matches!(reader.next_rgb565(), Err(Error::UnknownEndidness)));
+    assert_eq!(reader.current_offset(), 0);
+}
+
+pub(crate) fn remaining_matches_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(b"RIFF\x24\x08\x00\x00WAVE", Endidness::Little).unwrap();
+    let pattern = [
+        Some(b'R'),
+        Some(b'I'),
+        Some(b'F'),
+        Some(b'F'),
+        None,
+        None,
+        None,
+        None,
+        Some(b'W'),
+        Some(b'A'),
+        Some(b'V'),
+        Some(b'E'),
+    ];
+    assert!(reader.remaining_matches(&pattern).unwrap());
+    assert!(reader.remaining_matches(&[]).unwrap());
+    assert!(!reader
+        .remaining_matches(&[Some(b'R'), None, Some(b'X')])
+        .unwrap());
+    assert_eq!(reader.current_offset(), 0);
+    reader.advance_by(4).unwrap();
+    assert!(matches!(
+        reader.remaining_matches(&pattern),
+        Err(Error::NotEnoughData(12, 8))
+    ));
+}
+
+pub(crate) fn expect_all_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(
+        &[0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00],
+        10,
+        Endidness::Unknown,
+    )
+    .unwrap();
+    reader.advance_by(1).unwrap();
+    reader.expect_all(4, 0).unwrap();
+    assert_eq!(reader.current_offset(), 15);
+    assert!(matches!(
+        reader.expect_all(3, 0),
+        Err(Error::InvalidData(16))
+    ));
+    assert_eq!(reader.current_offset(), 15);
+    assert!(matches!(
+        reader.expect_all(4, 0),
+        Err(Error::NotEnoughData(4, 3))
+    ));
+}
+
+pub(crate) fn read_signature_with_version_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(b"GIF89a\x01\x00", Endidness::Little).unwrap();
+    assert_eq!(
+        reader.read_signature_with_version(b"GIF", 3).unwrap(),
+        b"89a"
+    );
+    assert_eq!(reader.current_offset(), 6);
+    reader.advance_to(0).unwrap();
+    assert!(matches!(
+        reader.read_signature_with_version(b"PNG", 3),
+        Err(Error::BadMagic(0))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+    assert!(matches!(
+        reader.read_signature_with_version(b"GIF", 6),
+        Err(Error::NotEnoughData(9, 8))
+    ));
+}
+
+const FOOTER_DATA: [u8; 16] = [
+    0xde, 0xad, 0xbe, 0xef, 0x00, 0x00, b'D', b'I', b'R', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a,
+];
+
+pub(crate) fn follow_footer_u32_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&FOOTER_DATA, 4, Endidness::Big).unwrap();
+    let directory = reader.follow_footer_u32(4).unwrap();
+    assert_eq!(directory.initial_offset(), 10);
+    assert_eq!(directory.as_ref(), b"DIR\x00\x00\x00");
+    assert_eq!(directory.next_u8().unwrap(), b'D');
+    assert_eq!(reader.current_offset(), 4);
+    assert!(matches!(
+        reader.follow_footer_u32(2),
+        Err(Error::NotEnoughData(4, 2))
+    ));
+    assert!(matches!(
+        reader.follow_footer_u32(30),
+        Err(Error::OffsetTooSmall(_))
+    ));
+    let reader = B::from_slice_with_offset(&FOOTER_DATA, 12, Endidness::Big).unwrap();
+    assert!(matches!(
+        reader.follow_footer_u32(4),
+        Err(Error::OffsetTooSmall(10))
+    ));
+}
+
+const MIDI_VLQ_DATA: [u8; 8] = [0x00, 0x7f, 0x81, 0x00, 0xff, 0x7f, 0xc0, 0x80];
+
+pub(crate) fn next_varint_custom_test<'r, B: BinReader<'r>>() {
+    let leb128 = B::from_slice(&ULEB128_DATA, Endidness::Unknown).unwrap();
+    let custom = B::from_slice(&ULEB128_DATA, Endidness::Unknown).unwrap();
+    while !leb128.is_empty() {
+        match leb128.next_uleb128() {
+            Ok(value) => {
+                assert_eq!(custom.next_varint_custom(7, true, true).unwrap(), value);
+                assert_eq!(custom.current_offset(), leb128.current_offset());
+            }
+            Err(_) => break,
+        }
+    }
+    let reader = B::from_slice(&MIDI_VLQ_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_varint_custom(7, false, true).unwrap(), 0);
+    assert_eq!(reader.next_varint_custom(7, false, true).unwrap(), 0x7f);
+    assert_eq!(reader.next_varint_custom(7, false, true).unwrap(), 0x80);
+    assert_eq!(reader.next_varint_custom(7, false, true).unwrap(), 0x3fff);
+    assert_eq!(reader.current_offset(), 6);
+    assert!(matches!(
+        reader.next_varint_custom(7, false, true),
+        Err(Error::NoMoreData)
+    ));
+    assert_eq!(reader.current_offset(), 6);
+    let reader = B::from_slice(&[0x03, 0x04], Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_varint_custom(4, true, false).unwrap(), 0x21);
+    let reader = B::from_slice(&[0xd0, 0x18, 0x8f, 0x05], Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_varint_custom(4, true, true).unwrap(), 0x3a);
+    assert_eq!(reader.next_varint_custom(4, true, true).unwrap(), 0x01);
+    assert_eq!(reader.current_offset(), 4);
+    let reader = B::from_slice(&[0xff; 11], Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.next_varint_custom(7, true, true),
+        Err(Error::VarintTooLong)
+    ));
+    assert!(matches!(
+        reader.next_varint_custom(8, true, true),
+        Err(Error::Other(_))
+    ));
+}
+
+const SQLITE_VARINT_DATA: [u8; 17] = [
+    0x7f, 0x81, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x88, 0x80, 0x80, 0x80,
+    0x80,
+];
+
+pub(crate) fn next_sqlite_varint_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&SQLITE_VARINT_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_sqlite_varint().unwrap(), 0x7f);
+    assert_eq!(reader.next_sqlite_varint().unwrap(), 0x80);
+    assert_eq!(reader.current_offset(), 3);
+    assert_eq!(reader.next_sqlite_varint().unwrap(), u64::MAX);
+    assert_eq!(reader.current_offset(), 12);
+    assert!(matches!(
+        reader.next_sqlite_varint(),
+        Err(Error::NoMoreData)
+    ));
+    assert_eq!(reader.current_offset(), 12);
+    let reader = B::from_slice(
+        &[0x88, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00],
+        Endidness::Unknown,
+    )
+    .unwrap();
+    assert_eq!(reader.next_sqlite_varint().unwrap(), 0x1000_0000_0000_0000);
+}
+
+pub(crate) fn leb128_at_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&ULEB128_DATA, 10, Endidness::Unknown).unwrap();
+    assert_eq!(reader.uleb128_at(11).unwrap(), 624485);
+    assert_eq!(reader.uleb128_at(14).unwrap(), 128);
+    assert!(matches!(reader.uleb128_at(16), Err(Error::NoMoreData)));
+    assert!(matches!(
+        reader.uleb128_at(9),
+        Err(Error::OffsetTooSmall(9))
+    ));
+    assert_eq!(reader.current_offset(), 10);
+    let reader = B::from_slice(&SLEB128_DATA, Endidness::Unknown).unwrap();
+    assert_eq!(reader.sleb128_at(5).unwrap(), 624485);
+    assert_eq!(reader.sleb128_at(3).unwrap(), -128);
+    assert_eq!(reader.current_offset(), 0);
+    let reader = B::from_slice(&ULEB128_TOO_LONG_DATA, Endidness::Unknown).unwrap();
+    assert!(matches!(reader.uleb128_at(0), Err(Error::VarintTooLong)));
+    assert_eq!(reader.sleb128_at(0).unwrap(), -1);
+    assert_eq!(reader.current_offset(), 0);
+}
+
+const CSTR_DATA: [u8; 16] = [
+    b'.', b't', b'e', b'x', b't', 0x00, 0x00, 0xff, 0xfe, 0x00, b'l', b'i', b'b', b'c', 0x00, b'x',
+];
+
+pub(crate) fn cstr_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice_with_offset(&CSTR_DATA, 4, Endidness::Unknown).unwrap();
+    assert_eq!(reader.cstr_at(14).unwrap(), b"libc");
+    assert_eq!(reader.current_offset(), 4);
+    assert_eq!(reader.next_cstr().unwrap(), b".text");
+    assert_eq!(reader.current_offset(), 10);
+    assert_eq!(reader.next_cstr_utf8().unwrap(), "");
+    assert_eq!(reader.current_offset(), 11);
+    assert!(matches!(reader.next_cstr_utf8(), Err(Error::Other(_))));
+    assert_eq!(reader.current_offset(), 11);
+    assert_eq!(reader.next_cstr().unwrap(), &[0xff, 0xfe]);
+    assert_eq!(reader.next_cstr_utf8().unwrap(), "libc");
+    assert_eq!(reader.current_offset(), 19);
+    assert!(matches!(reader.next_cstr(), Err(Error::NoMoreData)));
+    assert_eq!(reader.current_offset(), 19);
+    reader.advance_by(1).unwrap();
+    assert!(matches!(reader.next_cstr(), Err(Error::NoMoreData)));
+}
+
+pub(crate) fn next_utf8_chars_test<'r, B: BinReader<'r>>() {
+    let data = "añ€😀x".as_bytes();
+    let reader = B::from_slice_with_offset(data, 2, Endidness::Unknown).unwrap();
+    assert_eq!(reader.next_utf8_chars(0).unwrap(), "");
+    assert_eq!(reader.current_offset(), 2);
+    assert_eq!(reader.next_utf8_chars(4).unwrap(), "añ€😀");
+    assert_eq!(reader.current_offset(), 12);
+    assert!(matches!(reader.next_utf8_chars(2), Err(Error::NoMoreData)));
+    assert_eq!(reader.current_offset(), 12);
+    reader.advance_to(2).unwrap();
+    assert!(matches!(
+        reader.next_utf8_chars(usize::MAX),
+        Err(Error::NoMoreData)
+    ));
+    assert_eq!(reader.current_offset(), 2);
+    let reader = B::from_slice(&[0xe2, 0x82], Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.next_utf8_chars(1),
+        Err(Error::NotEnoughData(3, 2))
+    ));
+    let reader = B::from_slice(b"a\xe2\x28\xa1", Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.next_utf8_chars(2),
+        Err(Error::InvalidData(1))
+    ));
+    let reader = B::from_slice(b"\xff", Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.next_utf8_chars(1),
+        Err(Error::InvalidData(0))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+}
+
+pub(crate) fn read_cstr_list_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(b"ls\0-la\0\0\0x\0", Endidness::Unknown).unwrap();
+    assert_eq!(
+        reader.read_cstr_list().unwrap(),
+        vec![&b"ls"[..], &b"-la"[..]]
+    );
+    assert_eq!(reader.current_offset(), 8);
+    assert!(reader.read_cstr_list().unwrap().is_empty());
+    assert_eq!(reader.current_offset(), 9);
+    assert!(matches!(reader.read_cstr_list(), Err(Error::NoMoreData)));
+    assert_eq!(reader.current_offset(), 9);
+}
+
+pub(crate) fn read_geometry_commands_test<'r, B: BinReader<'r>>() {
+    use crate::GeometryCommand;
+    // The example polygon from the vector tile specification.
+    let reader = B::from_slice(
+        &[0x09, 0x06, 0x0c, 0x12, 0x0a, 0x0c, 0x18, 0x2c, 0x0f],
+        Endidness::Unknown,
+    )
+    .unwrap();
+    assert_eq!(
+        reader.read_geometry_commands().unwrap(),
+        vec![
+            GeometryCommand::MoveTo(3, 6),
+            GeometryCommand::LineTo(8, 12),
+            GeometryCommand::LineTo(20, 34),
+            GeometryCommand::ClosePath,
+        ]
+    );
+    assert!(reader.is_empty());
+    let reader = B::from_slice(&[0x09, 0x04, 0x04, 0x0b], Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_geometry_commands(),
+        Err(Error::InvalidData(3))
+    ));
+    let reader = B::from_slice(&[0x11, 0x04, 0x04, 0x02], Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_geometry_commands(),
+        Err(Error::NotEnoughData(4, 3))
+    ));
+    let reader = B::from_slice(&[0x11, 0x04, 0x04, 0x02, 0x80], Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_geometry_commands(),
+        Err(Error::NoMoreData)
+    ));
+    let reader = B::from_slice(&[0xf9, 0xff, 0xff, 0x7f], Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_geometry_commands(),
+        Err(Error::NotEnoughData(0x03ff_fffe, 0))
+    ));
+    let reader = B::from_slice(&[0xff, 0xff, 0xff, 0x7f], Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_geometry_commands(),
+        Err(Error::InvalidData(0))
+    ));
+    let reader = B::from_slice(&[0x09, 0x04, 0x04, 0x07], Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_geometry_commands(),
+        Err(Error::InvalidData(3))
+    ));
+}
+
+pub(crate) fn next_fixed_str_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(
+        b"abc\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        Endidness::Unknown,
+    )
+    .unwrap();
+    assert_eq!(reader.next_fixed_str(4).unwrap(), b"abc");
+    assert_eq!(reader.current_offset(), 4);
+    assert_eq!(reader.next_fixed_str(4).unwrap(), b"");
+    assert!(matches!(
+        reader.next_fixed_str(5),
+        Err(Error::NotEnoughData(5, 4))
+    ));
+    assert_eq!(reader.current_offset(), 8);
+}
+
+pub(crate) fn next_pascal_str_test<'r, B: BinReader<'r>>() {
+    let data = b"\x02hi\x00\x03abc\x00\x00\x00\x01z\x00\x09q";
+    let reader = B::from_slice(data, Endidness::Big).unwrap();
+    assert_eq!(reader.next_pascal_str_u8().unwrap(), b"hi");
+    assert_eq!(reader.next_pascal_str_u16().unwrap(), b"abc");
+    assert_eq!(reader.next_pascal_str_u32().unwrap(), b"z");
+    assert_eq!(reader.current_offset(), 13);
+    assert!(matches!(
+        reader.next_pascal_str_u16(),
+        Err(Error::NotEnoughData(9, 1))
+    ));
+    assert_eq!(reader.current_offset(), 13);
+    let reader = B::from_slice(data, Endidness::Unknown).unwrap();
+    reader.advance_to(3).unwrap();
+    assert!(matches!(
+        reader.next_pascal_str_u16(),
+        Err(Error::UnknownEndidness)
+    ));
+}
+
+pub(crate) fn read_soa_u32_test<'r, B: BinReader<'r>>() {
+    let data = (0..25).collect::<Vec<u8>>().leak();
+    let reader = B::from_slice(data, Endidness::Big).unwrap();
+    assert_eq!(
+        reader.read_soa_u32(2, 3).unwrap(),
+        vec![
+            vec![0x00010203, 0x08090a0b, 0x10111213],
+            vec![0x04050607, 0x0c0d0e0f, 0x14151617],
+        ]
+    );
+    assert_eq!(reader.current_offset(), 24);
+    reader.advance_to(0).unwrap();
+    assert!(matches!(
+        reader.read_soa_u32(2, 4),
+        Err(Error::NotEnoughData(32, 25))
+    ));
+    assert_eq!(reader.current_offset(), 0);
+    let reader = B::from_slice(data, Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.read_soa_u32(2, 3),
+        Err(Error::UnknownEndidness)
+    ));
+    assert!(reader.read_soa_u32(0, usize::MAX).unwrap().is_empty());
+    assert_eq!(reader.current_offset(), 0);
+    assert!(matches!(
+        reader.read_soa_u32(usize::MAX / 8, 0),
+        Err(Error::NotEnoughData(_, 25))
+    ));
+    assert_eq!(reader.read_soa_u32(6, 0).unwrap(), vec![Vec::new(); 6]);
+    assert_eq!(reader.current_offset(), 0);
+}
+
+const UTF16_CSTR_DATA: [u8; 14] = [
+    0x00, 0x48, 0x00, 0x69, 0x00, 0x00, 0xd8, 0x3d, 0xde, 0x00, 0x00, 0x00, 0xdc, 0x00,
+];
+
+pub(crate) fn next_utf16_cstr_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&UTF16_CSTR_DATA, Endidness::Big).unwrap();
+    assert_eq!(reader.next_utf16_cstr().unwrap(), "Hi");
+    assert_eq!(reader.current_offset(), 6);
+    assert_eq!(reader.next_utf16_cstr().unwrap(), "\u{1f600}");
+    assert_eq!(reader.current_offset(), 12);
+    assert!(matches!(reader.next_utf16_cstr(), Err(Error::NoMoreData)));
+    assert!(matches!(reader.next_utf16_fixed(1), Err(Error::Other(_))));
+    assert_eq!(reader.current_offset(), 12);
+    let reader = B::from_slice(&UTF16_CSTR_DATA, Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.next_utf16_cstr(),
+        Err(Error::UnknownEndidness)
+    ));
+}
+
+pub(crate) fn next_utf16_fixed_test<'r, B: BinReader<'r>>() {
+    let reader = B::from_slice(&UTF16_CSTR_DATA, Endidness::Big).unwrap();
+    assert_eq!(reader.next_utf16_fixed(3).unwrap(), "Hi\u{0}");
+    assert_eq!(reader.next_utf16_fixed(2).unwrap(), "\u{1f600}");
+    assert_eq!(reader.current_offset(), 10);
+    assert!(matches!(
+        reader.next_utf16_fixed(3),
+        Err(Error::NotEnoughData(6, 4))
+    ));
+    let reader = B::from_slice(&UTF16_CSTR_DATA[6..], Endidness::Big).unwrap();
+    assert!(matches!(reader.next_utf16_fixed(1), Err(Error::Other(_))));
+    assert_eq!(reader.current_offset(), 0);
+    let reader = B::from_slice(&UTF16_CSTR_DATA, Endidness::Unknown).unwrap();
+    assert!(matches!(
+        reader.next_utf16_fixed(1),
+        Err(Error::UnknownEndidness)
+    ));
+}
+
+pub(crate) fn bit_reader_test<'r, B: BinReader<'r>>() {
+    use crate::{BitOrder, BitReader};
+    let reader = B::from_slice(&[0b1011_0010, 0b0110_1001, 0xff], Endidness::Unknown).unwrap();
+    let mut bits = BitReader::new(&reader, BitOrder::MsbFirst);
+    assert_eq!(bits.read_bits(3).unwrap(), 5);
+    assert_eq!(bits.read_bits(3).unwrap(), 4);
+    assert_eq!(reader.current_offset(), 0);
+    assert_eq!(bits.read_bits(2).unwrap(), 2);
+    assert_eq!(reader.current_offset(), 1);
+    assert!(!bits.read_bit().unwrap());
+    assert!(bits.read_bit().unwrap());
+    assert_eq!(bits.bit_position(), 2);
+    bits.align().unwrap();
+    bits.align().unwrap();
+    assert_eq!(reader.current_offset(), 2);
+    assert!(matches!(bits.read_bits(9), Err(Error::NoMoreData)));
+    assert_eq!((reader.current_offset(), bits.bit_position()), (2, 0));
+
+    reader.advance_to(0).unwrap();
+    let mut bits = BitReader::new(&reader, BitOrder::LsbFirst);
+    assert_eq!(bits.read_bits(3).unwrap(), 0b010);
+    assert_eq!(bits.read_bits(7).unwrap(), 0b01_10110);
+    assert_eq!(reader.current_offset(), 1);
+    assert!(matches!(bits.read_bits(65), Err(Error::Other(_))));
+}
+
+#[cfg(feature = "msgpack")]
+pub(crate) fn next_msgpack_test<'r, B: BinReader<'r>>() {
+    use crate::MsgpackValue;
+    let reader = B::from_slice(
+        &[0x07, 0xa2, b'h', b'i', 0x93, 0x01, 0xff, 0xc0, 0x92, 0x01],
+        Endidness::Unknown,
+    )
+    .unwrap();
+    assert_eq!(reader.next_msgpack().unwrap(), MsgpackValue::UInt(7));
+    assert_eq!(
+        reader.next_msgpack().unwrap(),
+        MsgpackValue::String("hi".to_string())
+    );
+    assert_eq!(
+        reader.next_msgpack().unwrap(),
+        MsgpackValue::Array(vec![
+            MsgpackValue::UInt(1),
+            MsgpackValue::Int(-1),
+            MsgpackValue::Nil
+        ])
+    );
+    assert_eq!(reader.current_offset(), 8);
+    assert!(matches!(reader.next_msgpack(), Err(Error::Other(_))));
+    assert_eq!(reader.current_offset(), 8);
+}
+
+#[cfg(feature = "bytemuck")]
+pub(crate) fn read_pod_test<'r, B: BinReader<'r>>() {
+    #[repr(C, packed)]
+    #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    struct Header {
+        a: u16,
+        b: u32,
+    }
+    let mut data = vec![0xff];
+    data.extend_from_slice(&0x1234u16.to_ne_bytes());
+    data.extend_from_slice(&0xdead_beefu32.to_ne_bytes());
+    let reader = B::from_slice_with_offset(data.leak(), 10, Endidness::Big).unwrap();
+    let header: Header = reader.pod_at(11).unwrap();
+    assert_eq!({ header.a }, 0x1234);
+    assert_eq!({ header.b }, 0xdead_beef);
+    assert_eq!(reader.current_offset(), 10);
+    reader.advance_by(1).unwrap();
+    let header: Header = reader.read_pod().unwrap();
+    assert_eq!({ header.b }, 0xdead_beef);
+    assert_eq!(reader.current_offset(), 17);
+    reader.advance_to(12).unwrap();
+    assert!(matches!(
+        reader.read_pod::<Header>(),
+        Err(Error::NotEnoughData(6, 5))
+    ));
+    assert_eq!(reader.current_offset(), 12);
+}
@@ -1,3 +1,4 @@
+use crate::Endidness;
 use bytes::{BufMut as _, Bytes, BytesMut};
 use std::{fs, io, path::Path};
 
@@ -28,3 +29,123 @@ pub fn bytes_from_bufread<R: io::BufRead>(
         reader.consume(buf_len);
     }
 }
+
+/// Decodes a zigzag encoded integer (as used by Protocol Buffers, among others), where signed
+/// values are mapped to unsigned ones so that small magnitudes stay small.
+pub fn zigzag_decode(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+/// Expands the `bits` wide (between `4` and `8`) color channel stored in the low bits of `value`
+/// to 8 bits, replicating its high bits into the low ones so that the full range maps onto
+/// `0..=255`.
+pub(crate) fn expand_channel(value: u16, bits: u32) -> u8 {
+    let value = value & ((1 << bits) - 1);
+    ((value << (8 - bits)) | (value >> (2 * bits - 8))) as u8
+}
+
+/// Decodes an unsigned LEB128 value, pulling each byte from `next_byte`.
+pub(crate) fn decode_uleb128<F>(mut next_byte: F) -> crate::Result<u64>
+where
+    F: FnMut() -> crate::Result<u8>,
+{
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = next_byte()?;
+        let bits = (byte & 0x7f) as u64;
+        if shift > 63 || (shift == 63 && bits > 1) {
+            return Err(crate::Error::VarintTooLong);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+/// Decodes a signed LEB128 value, pulling each byte from `next_byte`.
+pub(crate) fn decode_sleb128<F>(mut next_byte: F) -> crate::Result<i64>
+where
+    F: FnMut() -> crate::Result<u8>,
+{
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = next_byte()?;
+        let bits = (byte & 0x7f) as i64;
+        if shift > 63 || (shift == 63 && bits != 0 && bits != 0x7f) {
+            return Err(crate::Error::VarintTooLong);
+        }
+        value |= bits << shift;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            if shift < 64 && byte & 0x40 != 0 {
+                value |= -1 << shift;
+            }
+            return Ok(value);
+        }
+    }
+}
+
+/// Splits `data` into UTF-16 code units using the provided endidness. Any odd trailing byte is
+/// ignored.
+pub(crate) fn utf16_units(
+    data: &[u8],
+    endidness: Endidness,
+) -> crate::Result<impl Iterator<Item = u16> + '_> {
+    let from_bytes = match endidness {
+        Endidness::Big => u16::from_be_bytes,
+        Endidness::Little => u16::from_le_bytes,
+        Endidness::Unknown => return Err(crate::Error::UnknownEndidness),
+    };
+    Ok(data
+        .chunks_exact(2)
+        .map(move |unit| from_bytes([unit[0], unit[1]])))
+}
+
+/// Makes sure that a reader holding `len` bytes of data can start at `initial_offset` without its
+/// offsets overflowing.
+pub(crate) fn validate_initial_offset(initial_offset: usize, len: usize) -> crate::Result<()> {
+    match initial_offset.checked_add(len) {
+        Some(_) => Ok(()),
+        None => Err(crate::Error::OffsetTooLarge(initial_offset)),
+    }
+}
+
+/// Applies a relative move of `num_bytes` to `offset`, returning an error instead of wrapping
+/// around.
+pub(crate) fn offset_by(offset: usize, num_bytes: isize) -> crate::Result<usize> {
+    if num_bytes < 0 {
+        offset
+            .checked_sub(num_bytes.unsigned_abs())
+            .ok_or(crate::Error::OffsetTooSmall(0))
+    } else {
+        offset
+            .checked_add(num_bytes as usize)
+            .ok_or(crate::Error::OffsetTooLarge(usize::MAX))
+    }
+}
+
+/// Moves the reader's cursor to `offset`, even if that's backwards while the monotonic check is
+/// on. This is for returning to a previously saved offset.
+pub(crate) fn rewind<'r, B: crate::BinReader<'r>>(reader: &B, offset: usize) -> crate::Result<()> {
+    if !reader.monotonic_check_enabled() {
+        return reader.advance_to(offset);
+    }
+    reader.disable_monotonic_check();
+    let result = reader.advance_to(offset);
+    reader.enable_monotonic_check();
+    result
+}
+
+/// Makes sure that moving from `from` to `to` doesn't go backwards when the monotonic check is
+/// enabled.
+pub(crate) fn check_monotonic(enabled: bool, from: usize, to: usize) -> crate::Result<()> {
+    if enabled && to < from {
+        Err(crate::Error::Other("non-monotonic advance".to_string()))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{Endidness, Error, Result};
+use binreader_macros::make_number_methods;
+
+mod slice;
+mod vec;
+
+pub use slice::SliceMutBinWriter;
+pub use vec::VecBinWriter;
+
+/// The write side of a [`BinReader`](crate::BinReader). Offsets and endidness work the same way
+/// as they do for a [`BinReader`](crate::BinReader), so data can be parsed, altered and then
+/// written back out using the same offsets.
+pub trait BinWriter: Sized {
+    /// The offset of the first byte of the data.
+    fn initial_offset(&self) -> usize;
+
+    /// The offset that the next write will start at.
+    fn current_offset(&self) -> usize;
+
+    /// The offset just past the last byte of the data.
+    fn upper_offset_limit(&self) -> usize;
+
+    /// The default endidness used when writing numbers.
+    fn endidness(&self) -> Endidness;
+
+    /// Changes the default endidness.
+    fn change_endidness(&mut self, endidness: Endidness);
+
+    /// Sets the [`BinWriter::current_offset`].
+    fn advance_to(&mut self, offset: usize) -> Result<()>;
+
+    /// Writes the provided bytes starting at the provided offset. This does not alter the
+    /// [`BinWriter::current_offset`].
+    fn write_bytes_at(&mut self, offset: usize, data: &[u8]) -> Result<()>;
+
+    /// Alters the [`BinWriter::current_offset`] by the given amount.
+    fn advance_by(&mut self, num_bytes: isize) -> Result<()> {
+        self.advance_to(crate::util::offset_by(self.current_offset(), num_bytes)?)
+    }
+
+    /// Writes the provided bytes at the [`BinWriter::current_offset`] and then advances past
+    /// them.
+    fn write_bytes(&mut self, data: &[u8]) -> Result<()> {
+        let offset = self.current_offset();
+        let end = offset
+            .checked_add(data.len())
+            .ok_or(Error::OffsetTooLarge(usize::MAX))?;
+        self.write_bytes_at(offset, data)?;
+        self.advance_to(end)
+    }
+
+    /// Writes a `u8` at the [`BinWriter::current_offset`] and then advances it by `1`.
+    fn write_u8(&mut self, value: u8) -> Result<()> {
+        self.write_bytes(&[value])
+    }
+
+    /// Writes an `i8` at the [`BinWriter::current_offset`] and then advances it by `1`.
+    fn write_i8(&mut self, value: i8) -> Result<()> {
+        self.write_bytes(&value.to_be_bytes())
+    }
+
+    /// Writes a `u8` at the provided offset without altering the [`BinWriter::current_offset`].
+    fn write_u8_at(&mut self, offset: usize, value: u8) -> Result<()> {
+        self.write_bytes_at(offset, &[value])
+    }
+
+    make_number_methods! {
+        /// Writes a numendlong endian `numname` at the [`BinWriter::current_offset`] and then
+        /// advances past it.
+        fn write_numname_numend(&mut self, value: _numname_) -> Result<()> {
+            self.write_bytes(&value.to_numend_bytes())
+        }
+    }
+
+    make_number_methods! {
+        /// Writes a numendlong endian `numname` at the provided offset without altering the
+        /// [`BinWriter::current_offset`].
+        fn write_numname_numend_at(&mut self, offset: usize, value: _numname_) -> Result<()> {
+            self.write_bytes_at(offset, &value.to_numend_bytes())
+        }
+    }
+
+    default_endidness_methods! {
+        write BinWriter;
+        u16: write_u16, write_u16_be, write_u16_le;
+        u32: write_u32, write_u32_be, write_u32_le;
+        u64: write_u64, write_u64_be, write_u64_le;
+        u128: write_u128, write_u128_be, write_u128_le;
+        i16: write_i16, write_i16_be, write_i16_le;
+        i32: write_i32, write_i32_be, write_i32_le;
+        i64: write_i64, write_i64_be, write_i64_le;
+        i128: write_i128, write_i128_be, write_i128_le;
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{BinWriter, Endidness, Error, Result};
+
+/// A [`BinWriter`] over a mutable slice. Since the slice can't grow, writes that would go past
+/// its end return an error instead.
+pub struct SliceMutBinWriter<'w> {
+    initial_offset: usize,
+    position: usize,
+    data: &'w mut [u8],
+    endidness: Endidness,
+}
+
+impl<'w> SliceMutBinWriter<'w> {
+    /// Creates a new [`SliceMutBinWriter`] whose initial offset is `0`.
+    pub fn from_slice(data: &'w mut [u8], endidness: Endidness) -> Self {
+        Self::from_slice_with_offset(data, 0, endidness)
+    }
+
+    /// Creates a new [`SliceMutBinWriter`] whose first byte is at `initial_offset`.
+    pub fn from_slice_with_offset(
+        data: &'w mut [u8],
+        initial_offset: usize,
+        endidness: Endidness,
+    ) -> Self {
+        Self {
+            initial_offset,
+            position: 0,
+            data,
+            endidness,
+        }
+    }
+
+    fn validate_offset(&self, offset: usize, size: usize) -> Result<()> {
+        if offset < self.initial_offset {
+            Err(Error::OffsetTooSmall(offset))
+        } else if offset > self.upper_offset_limit() {
+            Err(Error::OffsetTooLarge(offset))
+        } else if size > self.upper_offset_limit() - offset {
+            Err(Error::NotEnoughData(
+                size,
+                self.upper_offset_limit() - offset,
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'w> BinWriter for SliceMutBinWriter<'w> {
+    #[inline]
+    fn initial_offset(&self) -> usize {
+        self.initial_offset
+    }
+
+    #[inline]
+    fn current_offset(&self) -> usize {
+        self.initial_offset + self.position
+    }
+
+    #[inline]
+    fn upper_offset_limit(&self) -> usize {
+        self.initial_offset + self.data.len()
+    }
+
+    #[inline]
+    fn endidness(&self) -> Endidness {
+        self.endidness
+    }
+
+    fn change_endidness(&mut self, endidness: Endidness) {
+        self.endidness = endidness;
+    }
+
+    fn advance_to(&mut self, offset: usize) -> Result<()> {
+        self.validate_offset(offset, 0)?;
+        self.position = offset - self.initial_offset;
+        Ok(())
+    }
+
+    fn write_bytes_at(&mut self, offset: usize, data: &[u8]) -> Result<()> {
+        self.validate_offset(offset, data.len())?;
+        let start = offset - self.initial_offset;
+        self.data[start..start + data.len()].copy_from_slice(data);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BinReader, SliceRefBinReader};
+
+    #[test]
+    fn slice_writer_test() {
+        let mut data = [0; 8];
+        let mut writer = SliceMutBinWriter::from_slice_with_offset(&mut data, 10, Endidness::Big);
+        writer.write_u32(0xdead_beef).unwrap();
+        writer.write_i16_le(-2).unwrap();
+        assert_eq!(writer.current_offset(), 16);
+        assert!(matches!(
+            writer.write_u32(0),
+            Err(Error::NotEnoughData(4, 2))
+        ));
+        assert_eq!(writer.current_offset(), 16);
+        assert!(matches!(
+            writer.advance_to(19),
+            Err(Error::OffsetTooLarge(19))
+        ));
+        writer.write_u16_be_at(16, 0x0102).unwrap();
+        let reader = SliceRefBinReader::from_slice_with_offset(&data, 10, Endidness::Big).unwrap();
+        assert_eq!(reader.u32_at(10).unwrap(), 0xdead_beef);
+        assert_eq!(reader.i16_le_at(14).unwrap(), -2);
+        assert_eq!(reader.u16_at(16).unwrap(), 0x0102);
+    }
+}
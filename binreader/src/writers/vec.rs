@@ -0,0 +1,150 @@
+use crate::{BinWriter, Endidness, Error, Result};
+
+/// A [`BinWriter`] backed by a growable [`Vec`]. Writing past the end of the data grows it, and
+/// any gap between the old end and the start of the write is filled with zeros.
+pub struct VecBinWriter {
+    initial_offset: usize,
+    position: usize,
+    data: Vec<u8>,
+    endidness: Endidness,
+}
+
+impl VecBinWriter {
+    /// Creates a new, empty [`VecBinWriter`] whose initial offset is `0`.
+    pub fn new(endidness: Endidness) -> Self {
+        Self::from_vec_with_offset(Vec::new(), 0, endidness)
+    }
+
+    /// Creates a new [`VecBinWriter`] over existing data, with the cursor at its start.
+    pub fn from_vec(data: Vec<u8>, endidness: Endidness) -> Self {
+        Self::from_vec_with_offset(data, 0, endidness)
+    }
+
+    /// Creates a new [`VecBinWriter`] over existing data whose first byte is at
+    /// `initial_offset`, with the cursor at its start.
+    pub fn from_vec_with_offset(
+        data: Vec<u8>,
+        initial_offset: usize,
+        endidness: Endidness,
+    ) -> Self {
+        Self {
+            initial_offset,
+            position: 0,
+            data,
+            endidness,
+        }
+    }
+
+    /// The data written so far.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Consumes the writer, returning the data that was written.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl BinWriter for VecBinWriter {
+    #[inline]
+    fn initial_offset(&self) -> usize {
+        self.initial_offset
+    }
+
+    #[inline]
+    fn current_offset(&self) -> usize {
+        self.initial_offset + self.position
+    }
+
+    #[inline]
+    fn upper_offset_limit(&self) -> usize {
+        self.initial_offset + self.data.len()
+    }
+
+    #[inline]
+    fn endidness(&self) -> Endidness {
+        self.endidness
+    }
+
+    fn change_endidness(&mut self, endidness: Endidness) {
+        self.endidness = endidness;
+    }
+
+    fn advance_to(&mut self, offset: usize) -> Result<()> {
+        if offset < self.initial_offset {
+            return Err(Error::OffsetTooSmall(offset));
+        }
+        self.position = offset - self.initial_offset;
+        Ok(())
+    }
+
+    fn write_bytes_at(&mut self, offset: usize, data: &[u8]) -> Result<()> {
+        if offset < self.initial_offset {
+            return Err(Error::OffsetTooSmall(offset));
+        }
+        let start = offset - self.initial_offset;
+        let end = start
+            .checked_add(data.len())
+            .ok_or(Error::OffsetTooLarge(usize::MAX))?;
+        if end > self.data.len() {
+            self.data.resize(end, 0);
+        }
+        self.data[start..end].copy_from_slice(data);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BinReader, SliceRefBinReader};
+
+    #[test]
+    fn vec_writer_test() {
+        let mut writer = VecBinWriter::new(Endidness::Big);
+        writer.write_u8(0xaa).unwrap();
+        writer.write_u16(0x0102).unwrap();
+        writer.write_u32_le(0x0304_0506).unwrap();
+        writer.write_i8(-1).unwrap();
+        assert_eq!(writer.current_offset(), 8);
+        writer.advance_by(2).unwrap();
+        writer.write_i16(-2).unwrap();
+        assert_eq!(
+            writer.as_slice(),
+            &[0xaa, 0x01, 0x02, 0x06, 0x05, 0x04, 0x03, 0xff, 0x00, 0x00, 0xff, 0xfe]
+        );
+        writer.write_u16_le_at(8, 0x0807).unwrap();
+        writer.change_endidness(Endidness::Unknown);
+        assert!(matches!(writer.write_u32(0), Err(Error::UnknownEndidness)));
+        let data = writer.into_inner();
+        let reader = SliceRefBinReader::from_slice(&data, Endidness::Big).unwrap();
+        assert_eq!(reader.u8_at(0).unwrap(), 0xaa);
+        assert_eq!(reader.u16_at(1).unwrap(), 0x0102);
+        assert_eq!(reader.u32_le_at(3).unwrap(), 0x0304_0506);
+        assert_eq!(reader.u16_le_at(8).unwrap(), 0x0807);
+        assert_eq!(reader.i16_at(10).unwrap(), -2);
+    }
+
+    #[test]
+    fn vec_writer_round_trip_test() {
+        let mut writer = VecBinWriter::from_vec_with_offset(vec![0; 8], 100, Endidness::Little);
+        writer.advance_to(104).unwrap();
+        writer.write_u64(0x1122_3344_5566_7788).unwrap();
+        assert_eq!(writer.upper_offset_limit(), 112);
+        assert!(matches!(
+            writer.advance_to(99),
+            Err(Error::OffsetTooSmall(99))
+        ));
+        assert!(matches!(
+            writer.write_u8_at(50, 0),
+            Err(Error::OffsetTooSmall(50))
+        ));
+        let reader =
+            SliceRefBinReader::from_slice_with_offset(writer.as_slice(), 100, Endidness::Little)
+                .unwrap();
+        assert_eq!(reader.u32_at(100).unwrap(), 0);
+        assert_eq!(reader.u64_at(104).unwrap(), 0x1122_3344_5566_7788);
+    }
+}
@@ -0,0 +1,281 @@
+#![no_main]
+use binreader::{BinReader, Endidness, RandomAccessBinReader, SliceRefBinReader};
+use libfuzzer_sys::{
+    arbitrary::{self, Arbitrary},
+    fuzz_target,
+};
+use std::io::{Seek, SeekFrom};
+
+#[derive(Arbitrary, Debug)]
+enum FuzzEndidness {
+    Big,
+    Little,
+    Unknown,
+}
+
+impl From<&FuzzEndidness> for Endidness {
+    fn from(endidness: &FuzzEndidness) -> Self {
+        match endidness {
+            FuzzEndidness::Big => Self::Big,
+            FuzzEndidness::Little => Self::Little,
+            FuzzEndidness::Unknown => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum FuzzSeekFrom {
+    Start(u64),
+    End(i64),
+    Current(i64),
+}
+
+impl From<&FuzzSeekFrom> for SeekFrom {
+    fn from(pos: &FuzzSeekFrom) -> Self {
+        match pos {
+            FuzzSeekFrom::Start(to) => Self::Start(*to),
+            FuzzSeekFrom::End(by) => Self::End(*by),
+            FuzzSeekFrom::Current(by) => Self::Current(*by),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum Method {
+    AdvanceTo(usize),
+    AdvanceBy(isize),
+    AdvanceFromEnd(usize),
+    Skip(usize),
+    AlignTo(usize),
+    NextNBytes(usize),
+    GetRemaining,
+    RelativeOffset(usize),
+    NextBytesAre(Vec<u8>),
+    BytesAt(usize, u8),
+    Subseq(usize, usize),
+    Range(usize, usize),
+    NextBytes(u8),
+    U8At(usize),
+    U16At(usize),
+    U32BeAt(usize),
+    I64LeAt(usize),
+    U128At(usize),
+    CurrentU32Le,
+    NextU8,
+    NextI8,
+    NextU16,
+    NextI32Be,
+    NextU64Le,
+    NextI128,
+    SliceReader(usize, usize),
+    SliceReaderWithOffset(usize, usize, usize),
+    NextNBytesAsReader(usize),
+    ReadHexField(usize),
+    NextHexString(usize),
+    SyncTo(Vec<u8>, bool),
+    InetChecksum(usize, usize),
+    NextSizedReaderU32,
+    ReadUleb128Vec(usize),
+    ReadDeltaOffsetTable(usize, usize),
+    ReadBitpacked(u8, usize),
+    ImpliedCount(usize, usize, bool),
+    NextSmpteTimecode,
+    ReadFrontCodedStrings(usize),
+    ReadKvPairsU32(usize),
+    ReadKlvMap(usize),
+    ReadZigzagDeltaI32(usize, i32),
+    IntAtWith(usize, usize, FuzzEndidness),
+    ReadCappedArray(usize, usize),
+    ReadValidatedArray(usize),
+    ReadPresenceBitmap(usize),
+    NextChunk(FuzzEndidness, usize),
+    IndexChunks(FuzzEndidness, usize),
+    ReadUtf16FixedField(usize),
+    ReadRleU8(usize, usize),
+    NextU32Strided(usize),
+    ReadFieldColumnU32(usize, usize, usize),
+    RecordAtU32Array(usize, usize, usize),
+    ReadScaledF64(usize, usize, bool),
+    NextSampleNormalized(usize),
+    ReadStereoI16Vec(usize),
+    ExpectAll(usize, u8),
+    ReadSignatureWithVersion(Vec<u8>, usize),
+    FollowFooterU32(usize),
+    NextVarintCustom(u8, bool, bool),
+    NextUtf8Chars(usize),
+    ReadGeometryCommands,
+    NextFixedStr(usize),
+    ReadSoaU32(usize, usize),
+    NextUtf16Fixed(usize),
+    ReadAlignedFieldU32(usize),
+    ReadU32While(u32),
+    Read(u16),
+    Seek(FuzzSeekFrom),
+    FillBuf,
+    Consume(usize),
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    data: Vec<u8>,
+    initial_offset: usize,
+    endidness: FuzzEndidness,
+    methods: Vec<Method>,
+}
+
+fn run<'r, B>(reader: &mut B, methods: &[Method])
+where
+    B: BinReader<'r> + Seek,
+{
+    for method in methods {
+        let _ = match method {
+            Method::AdvanceTo(offset) => reader.advance_to(*offset).map(drop),
+            Method::AdvanceBy(num_bytes) => reader.advance_by(*num_bytes).map(drop),
+            Method::AdvanceFromEnd(back) => reader.advance_from_end(*back).map(drop),
+            Method::Skip(num_bytes) => reader.skip(*num_bytes).map(drop),
+            Method::AlignTo(alignment) => reader.align_to(*alignment).map(drop),
+            Method::NextNBytes(num_bytes) => reader.next_n_bytes(*num_bytes).map(drop),
+            Method::GetRemaining => reader.get_remaining().map(drop),
+            Method::RelativeOffset(offset) => reader.relative_offset(*offset).map(drop),
+            Method::NextBytesAre(prefix) => reader.next_bytes_are(prefix).map(drop),
+            Method::BytesAt(offset, len) => reader.bytes_at(*offset, &mut vec![0; *len as usize]),
+            Method::Subseq(offset, num_bytes) => reader.subseq(*offset, *num_bytes).map(drop),
+            Method::Range(start, end) => reader.range(*start, *end).map(drop),
+            Method::NextBytes(len) => reader.next_bytes(&mut vec![0; *len as usize]),
+            Method::U8At(offset) => reader.u8_at(*offset).map(drop),
+            Method::U16At(offset) => reader.u16_at(*offset).map(drop),
+            Method::U32BeAt(offset) => reader.u32_be_at(*offset).map(drop),
+            Method::I64LeAt(offset) => reader.i64_le_at(*offset).map(drop),
+            Method::U128At(offset) => reader.u128_at(*offset).map(drop),
+            Method::CurrentU32Le => reader.current_u32_le().map(drop),
+            Method::NextU8 => reader.next_u8().map(drop),
+            Method::NextI8 => reader.next_i8().map(drop),
+            Method::NextU16 => reader.next_u16().map(drop),
+            Method::NextI32Be => reader.next_i32_be().map(drop),
+            Method::NextU64Le => reader.next_u64_le().map(drop),
+            Method::NextI128 => reader.next_i128().map(drop),
+            Method::SliceReader(start, end) => reader.slice_reader(*start, *end).map(drop),
+            Method::SliceReaderWithOffset(start, offset, end) => reader
+                .slice_reader_with_offset(*start, *offset, *end)
+                .map(drop),
+            Method::NextNBytesAsReader(num_bytes) => {
+                reader.next_n_bytes_as_reader(*num_bytes).map(drop)
+            }
+            Method::ReadHexField(len) => reader.read_hex_field(*len).map(drop),
+            Method::NextHexString(num_bytes) => reader.next_hex_string(*num_bytes).map(drop),
+            Method::SyncTo(pattern, error_if_missing) => {
+                reader.sync_to(pattern, *error_if_missing).map(drop)
+            }
+            Method::InetChecksum(start, end) => reader.inet_checksum(*start, *end).map(drop),
+            Method::NextSizedReaderU32 => reader.next_sized_reader_u32().map(drop),
+            Method::ReadUleb128Vec(count) => reader.read_uleb128_vec(*count).map(drop),
+            Method::ReadDeltaOffsetTable(count, base) => {
+                reader.read_delta_offset_table(*count, *base).map(drop)
+            }
+            Method::ReadBitpacked(bit_width, count) => {
+                reader.read_bitpacked(*bit_width, *count).map(drop)
+            }
+            Method::ImpliedCount(total_size, element_size, allow_remainder) => reader
+                .implied_count(*total_size, *element_size, *allow_remainder)
+                .map(drop),
+            Method::NextSmpteTimecode => reader.next_smpte_timecode().map(drop),
+            Method::ReadFrontCodedStrings(count) => {
+                reader.read_front_coded_strings(*count).map(drop)
+            }
+            Method::ReadKvPairsU32(count) => reader.read_kv_pairs_u32(*count).map(drop),
+            Method::ReadKlvMap(count) => reader.read_klv_map(*count).map(drop),
+            Method::ReadZigzagDeltaI32(count, start) => {
+                reader.read_zigzag_delta_i32(*count, *start).map(drop)
+            }
+            Method::IntAtWith(offset, width, endidness) => reader
+                .int_at_with(*offset, *width, Endidness::from(endidness))
+                .map(drop),
+            Method::ReadCappedArray(count, max_count) => reader
+                .read_capped_array(*count, *max_count, |r| r.next_u8())
+                .map(drop),
+            Method::ReadValidatedArray(count) => reader
+                .read_validated_array(*count, |r| r.next_u16(), |_| Ok(()))
+                .map(drop),
+            Method::ReadPresenceBitmap(field_count) => {
+                reader.read_presence_bitmap(*field_count).map(drop)
+            }
+            Method::NextChunk(endidness, pad_to) => reader
+                .next_chunk(Endidness::from(endidness), *pad_to)
+                .map(drop),
+            Method::IndexChunks(endidness, pad_to) => reader
+                .index_chunks(Endidness::from(endidness), *pad_to)
+                .map(drop),
+            Method::ReadUtf16FixedField(code_units) => {
+                reader.read_utf16_fixed_field(*code_units).map(drop)
+            }
+            Method::ReadRleU8(pair_count, max_len) => {
+                reader.read_rle_u8(*pair_count, *max_len).map(drop)
+            }
+            Method::NextU32Strided(stride) => reader.next_u32_strided(*stride).map(drop),
+            Method::ReadFieldColumnU32(record_stride, field_offset, count) => reader
+                .read_field_column_u32(*record_stride, *field_offset, *count)
+                .map(drop),
+            Method::RecordAtU32Array(base, index, fields) => {
+                reader.record_at_u32_array(*base, *index, *fields).map(drop)
+            }
+            Method::ReadScaledF64(count, raw_width, signed) => reader
+                .read_scaled_f64(*count, 0.5, 1.0, *raw_width, *signed)
+                .map(drop),
+            Method::NextSampleNormalized(width) => reader.next_sample_normalized(*width).map(drop),
+            Method::ReadStereoI16Vec(frames) => reader.read_stereo_i16_vec(*frames).map(drop),
+            Method::ExpectAll(len, byte) => reader.expect_all(*len, *byte).map(drop),
+            Method::ReadSignatureWithVersion(fixed, version_len) => reader
+                .read_signature_with_version(fixed, *version_len)
+                .map(drop),
+            Method::FollowFooterU32(footer_offset_from_end) => {
+                reader.follow_footer_u32(*footer_offset_from_end).map(drop)
+            }
+            Method::NextVarintCustom(bits_per_byte, little_endian_groups, continuation_high) => {
+                reader
+                    .next_varint_custom(*bits_per_byte, *little_endian_groups, *continuation_high)
+                    .map(drop)
+            }
+            Method::NextUtf8Chars(char_count) => reader.next_utf8_chars(*char_count).map(drop),
+            Method::ReadGeometryCommands => reader.read_geometry_commands().map(drop),
+            Method::NextFixedStr(len) => reader.next_fixed_str(*len).map(drop),
+            Method::ReadSoaU32(field_count, record_count) => {
+                reader.read_soa_u32(*field_count, *record_count).map(drop)
+            }
+            Method::NextUtf16Fixed(units) => reader.next_utf16_fixed(*units).map(drop),
+            Method::ReadAlignedFieldU32(alignment) => {
+                reader.read_aligned_field_u32(*alignment).map(drop)
+            }
+            Method::ReadU32While(max) => reader.read_u32_while(|value| value <= *max).map(drop),
+            Method::Read(len) => {
+                let _ = reader.read(&mut vec![0; *len as usize]);
+                Ok(())
+            }
+            Method::Seek(pos) => {
+                let _ = reader.seek(SeekFrom::from(pos));
+                Ok(())
+            }
+            Method::FillBuf => {
+                let _ = reader.fill_buf();
+                Ok(())
+            }
+            Method::Consume(amt) => {
+                reader.consume(*amt);
+                Ok(())
+            }
+        };
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let endidness = Endidness::from(&input.endidness);
+    if let Ok(mut reader) =
+        SliceRefBinReader::from_slice_with_offset(&input.data, input.initial_offset, endidness)
+    {
+        run(&mut reader, &input.methods);
+    }
+    if let Ok(mut reader) =
+        RandomAccessBinReader::from_slice_with_offset(&input.data, input.initial_offset, endidness)
+    {
+        run(&mut reader, &input.methods);
+    }
+});
@@ -36,13 +36,18 @@ make_add_macro! {
     type: ::std::io::Seek;
     body: {
         fn seek(&mut self, pos: ::std::io::SeekFrom) -> ::std::io::Result<u64> {
-            match pos {
-                ::std::io::SeekFrom::Start(to) => self.advance_to(to as usize)?,
-                ::std::io::SeekFrom::Current(by) => self.advance_to(
-                    (self.current_offset() as i64 + by) as usize)?,
-                ::std::io::SeekFrom::End(point) => self.advance_to(
-                    (self.upper_offset_limit() as i64 - point) as usize)?,
+            let offset = match pos {
+                ::std::io::SeekFrom::Start(to) => to as i128,
+                ::std::io::SeekFrom::Current(by) => self.current_offset() as i128 + by as i128,
+                ::std::io::SeekFrom::End(by) => self.upper_offset_limit() as i128 + by as i128,
             };
+            let offset = <usize as ::std::convert::TryFrom<i128>>::try_from(offset).map_err(|_| {
+                ::std::io::Error::new(
+                    ::std::io::ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                )
+            })?;
+            self.advance_to(offset)?;
             Ok(self.current_offset() as u64)
         }
     }
@@ -1,4 +1,5 @@
 #[macro_export]
+#[allow(clippy::crate_in_macro_def)]
 macro_rules! test_reader {
     ($reader:ident) => {
         #[test]
@@ -35,5 +36,479 @@ macro_rules! test_reader {
         fn test_advance_to() {
             crate::testing::test_advance_to::<$reader>();
         }
+
+        #[test]
+        fn read_hex_field_test() {
+            crate::testing::read_hex_field_test::<$reader>();
+        }
+
+        #[test]
+        fn sync_to_test() {
+            crate::testing::sync_to_test::<$reader>();
+        }
+
+        #[test]
+        fn inet_checksum_test() {
+            crate::testing::inet_checksum_test::<$reader>();
+        }
+
+        #[cfg(feature = "snappy")]
+        #[test]
+        fn snappy_test() {
+            crate::testing::snappy_test::<$reader>();
+        }
+
+        #[cfg(feature = "lz4")]
+        #[test]
+        fn lz4_test() {
+            crate::testing::lz4_test::<$reader>();
+        }
+
+        #[test]
+        fn next_sized_reader_test() {
+            crate::testing::next_sized_reader_test::<$reader>();
+        }
+
+        #[cfg(feature = "scroll")]
+        #[test]
+        fn scroll_test() {
+            crate::testing::scroll_test::<$reader>();
+        }
+
+        #[test]
+        fn read_uleb128_vec_test() {
+            crate::testing::read_uleb128_vec_test::<$reader>();
+        }
+
+        #[test]
+        fn region_tracking_test() {
+            crate::testing::region_tracking_test::<$reader>();
+        }
+
+        #[test]
+        fn read_bitpacked_test() {
+            crate::testing::read_bitpacked_test::<$reader>();
+        }
+
+        #[test]
+        fn implied_count_test() {
+            crate::testing::implied_count_test::<$reader>();
+        }
+
+        #[test]
+        fn next_smpte_timecode_test() {
+            crate::testing::next_smpte_timecode_test::<$reader>();
+        }
+
+        #[test]
+        fn read_front_coded_strings_test() {
+            crate::testing::read_front_coded_strings_test::<$reader>();
+        }
+
+        #[test]
+        fn read_kv_pairs_u32_test() {
+            crate::testing::read_kv_pairs_u32_test::<$reader>();
+        }
+
+        #[test]
+        fn read_zigzag_delta_i32_test() {
+            crate::testing::read_zigzag_delta_i32_test::<$reader>();
+        }
+
+        #[test]
+        fn out_of_bounds_test() {
+            crate::testing::out_of_bounds_test::<$reader>();
+        }
+
+        #[test]
+        fn next_varint_string_test() {
+            crate::testing::next_varint_string_test::<$reader>();
+        }
+
+        #[test]
+        fn check_constraints_test() {
+            crate::testing::check_constraints_test::<$reader>();
+        }
+
+        #[test]
+        fn int_at_with_test() {
+            crate::testing::int_at_with_test::<$reader>();
+        }
+
+        #[test]
+        fn read_capped_array_test() {
+            crate::testing::read_capped_array_test::<$reader>();
+        }
+
+        #[test]
+        fn read_presence_bitmap_test() {
+            crate::testing::read_presence_bitmap_test::<$reader>();
+        }
+
+        #[test]
+        fn next_chunk_test() {
+            crate::testing::next_chunk_test::<$reader>();
+        }
+
+        #[test]
+        fn read_to_end_u32_test() {
+            crate::testing::read_to_end_u32_test::<$reader>();
+        }
+
+        #[test]
+        fn next_asn1_tlv_test() {
+            crate::testing::next_asn1_tlv_test::<$reader>();
+        }
+
+        #[test]
+        fn read_validated_array_test() {
+            crate::testing::read_validated_array_test::<$reader>();
+        }
+
+        #[test]
+        fn next_guid_string_test() {
+            crate::testing::next_guid_string_test::<$reader>();
+        }
+
+        #[test]
+        fn read_utf16_fixed_field_test() {
+            crate::testing::read_utf16_fixed_field_test::<$reader>();
+        }
+
+        #[test]
+        fn xor_checksum_test() {
+            crate::testing::xor_checksum_test::<$reader>();
+        }
+
+        #[test]
+        fn enter_frame_u32_test() {
+            crate::testing::enter_frame_u32_test::<$reader>();
+        }
+
+        #[test]
+        fn next_sleb128_test() {
+            crate::testing::next_sleb128_test::<$reader>();
+        }
+
+        #[test]
+        fn next_sleb128p1_test() {
+            crate::testing::next_sleb128p1_test::<$reader>();
+        }
+
+        #[test]
+        fn read_rle_u8_test() {
+            crate::testing::read_rle_u8_test::<$reader>();
+        }
+
+        #[test]
+        fn u32_conditional_test() {
+            crate::testing::u32_conditional_test::<$reader>();
+        }
+
+        #[test]
+        fn index_chunks_test() {
+            crate::testing::index_chunks_test::<$reader>();
+        }
+
+        #[test]
+        fn next_7bit_encoded_int_test() {
+            crate::testing::next_7bit_encoded_int_test::<$reader>();
+        }
+
+        #[test]
+        fn cow_range_test() {
+            crate::testing::cow_range_test::<$reader>();
+        }
+
+        #[test]
+        fn next_u16_enum_or_test() {
+            crate::testing::next_u16_enum_or_test::<$reader>();
+        }
+
+        #[test]
+        fn read_with_tail_test() {
+            crate::testing::read_with_tail_test::<$reader>();
+        }
+
+        #[test]
+        fn read_field_column_u32_test() {
+            crate::testing::read_field_column_u32_test::<$reader>();
+        }
+
+        #[test]
+        fn verify_declared_size_test() {
+            crate::testing::verify_declared_size_test::<$reader>();
+        }
+
+        #[test]
+        fn next_version_triple_test() {
+            crate::testing::next_version_triple_test::<$reader>();
+        }
+
+        #[test]
+        fn dispatch_u8_test() {
+            crate::testing::dispatch_u8_test::<$reader>();
+        }
+
+        #[test]
+        fn locator_test() {
+            crate::testing::locator_test::<$reader>();
+        }
+
+        #[cfg(feature = "chrono")]
+        #[test]
+        fn next_days_since_epoch_test() {
+            crate::testing::next_days_since_epoch_test::<$reader>();
+        }
+
+        #[test]
+        fn monotonic_check_test() {
+            crate::testing::monotonic_check_test::<$reader>();
+        }
+
+        #[test]
+        fn read_scaled_f64_test() {
+            crate::testing::read_scaled_f64_test::<$reader>();
+        }
+
+        #[test]
+        fn remaining_matches_test() {
+            crate::testing::remaining_matches_test::<$reader>();
+        }
+
+        #[test]
+        fn follow_footer_u32_test() {
+            crate::testing::follow_footer_u32_test::<$reader>();
+        }
+
+        #[test]
+        fn next_varint_custom_test() {
+            crate::testing::next_varint_custom_test::<$reader>();
+        }
+
+        #[test]
+        fn next_sqlite_varint_test() {
+            crate::testing::next_sqlite_varint_test::<$reader>();
+        }
+
+        #[test]
+        fn leb128_at_test() {
+            crate::testing::leb128_at_test::<$reader>();
+        }
+
+        #[test]
+        fn cstr_test() {
+            crate::testing::cstr_test::<$reader>();
+        }
+
+        #[test]
+        fn read_geometry_commands_test() {
+            crate::testing::read_geometry_commands_test::<$reader>();
+        }
+
+        #[test]
+        fn next_fixed_str_test() {
+            crate::testing::next_fixed_str_test::<$reader>();
+        }
+
+        #[test]
+        fn next_pascal_str_test() {
+            crate::testing::next_pascal_str_test::<$reader>();
+        }
+
+        #[test]
+        fn read_soa_u32_test() {
+            crate::testing::read_soa_u32_test::<$reader>();
+        }
+
+        #[test]
+        fn next_utf16_cstr_test() {
+            crate::testing::next_utf16_cstr_test::<$reader>();
+        }
+
+        #[test]
+        fn next_utf16_fixed_test() {
+            crate::testing::next_utf16_fixed_test::<$reader>();
+        }
+
+        #[test]
+        fn bit_reader_test() {
+            crate::testing::bit_reader_test::<$reader>();
+        }
+
+        #[cfg(feature = "msgpack")]
+        #[test]
+        fn next_msgpack_test() {
+            crate::testing::next_msgpack_test::<$reader>();
+        }
+
+        #[test]
+        fn expect_all_test() {
+            crate::testing::expect_all_test::<$reader>();
+        }
+
+        #[test]
+        fn next_sample_normalized_test() {
+            crate::testing::next_sample_normalized_test::<$reader>();
+        }
+
+        #[test]
+        fn align_to_test() {
+            crate::testing::align_to_test::<$reader>();
+        }
+
+        #[test]
+        fn read_signature_with_version_test() {
+            crate::testing::read_signature_with_version_test::<$reader>();
+        }
+
+        #[test]
+        fn bookmark_test() {
+            crate::testing::bookmark_test::<$reader>();
+        }
+
+        #[test]
+        fn flag_at_test() {
+            crate::testing::flag_at_test::<$reader>();
+        }
+
+        #[test]
+        fn advance_from_end_test() {
+            crate::testing::advance_from_end_test::<$reader>();
+        }
+
+        #[test]
+        fn skip_sized_u32_test() {
+            crate::testing::skip_sized_u32_test::<$reader>();
+        }
+
+        #[test]
+        fn iter_bytes_test() {
+            crate::testing::iter_bytes_test::<$reader>();
+        }
+
+        #[test]
+        fn read_klv_map_test() {
+            crate::testing::read_klv_map_test::<$reader>();
+        }
+
+        #[test]
+        fn iter_numbers_test() {
+            crate::testing::iter_numbers_test::<$reader>();
+        }
+
+        #[test]
+        fn record_at_u32_array_test() {
+            crate::testing::record_at_u32_array_test::<$reader>();
+        }
+
+        #[test]
+        fn read_delta_offset_table_test() {
+            crate::testing::read_delta_offset_table_test::<$reader>();
+        }
+
+        #[test]
+        fn next_hex_string_test() {
+            crate::testing::next_hex_string_test::<$reader>();
+        }
+
+        #[test]
+        fn find_bytes_test() {
+            crate::testing::find_bytes_test::<$reader>();
+        }
+
+        #[cfg(feature = "zlib")]
+        #[test]
+        fn zlib_test() {
+            crate::testing::zlib_test::<$reader>();
+        }
+
+        #[test]
+        fn next_typed_compressed_block_test() {
+            crate::testing::next_typed_compressed_block_test::<$reader>();
+        }
+
+        #[test]
+        fn basic_ne_test() {
+            crate::testing::basic_ne_test::<$reader>();
+        }
+
+        #[test]
+        fn read_cstr_list_test() {
+            crate::testing::read_cstr_list_test::<$reader>();
+        }
+
+        #[cfg(feature = "bytemuck")]
+        #[test]
+        fn read_pod_test() {
+            crate::testing::read_pod_test::<$reader>();
+        }
+
+        #[test]
+        fn next_u32_strided_test() {
+            crate::testing::next_u32_strided_test::<$reader>();
+        }
+
+        #[test]
+        fn remaining_record_count_test() {
+            crate::testing::remaining_record_count_test::<$reader>();
+        }
+
+        #[test]
+        fn stereo_i16_test() {
+            crate::testing::stereo_i16_test::<$reader>();
+        }
+
+        #[cfg(feature = "crc")]
+        #[test]
+        fn read_crc_protected_record_test() {
+            crate::testing::read_crc_protected_record_test::<$reader>();
+        }
+
+        #[test]
+        fn next_utf8_chars_test() {
+            crate::testing::next_utf8_chars_test::<$reader>();
+        }
+
+        #[test]
+        fn next_bytes_are_test() {
+            crate::testing::next_bytes_are_test::<$reader>();
+        }
+
+        #[cfg(feature = "crc")]
+        #[test]
+        fn next_n_bytes_checked_test() {
+            crate::testing::next_n_bytes_checked_test::<$reader>();
+        }
+
+        #[test]
+        fn next_wasm_section_test() {
+            crate::testing::next_wasm_section_test::<$reader>();
+        }
+
+        #[test]
+        fn u8_at_after_reads_test() {
+            crate::testing::u8_at_after_reads_test::<$reader>();
+        }
+
+        #[test]
+        fn read_aligned_field_u32_test() {
+            crate::testing::read_aligned_field_u32_test::<$reader>();
+        }
+
+        #[test]
+        fn validate_offset_large_size_test() {
+            crate::testing::validate_offset_large_size_test::<$reader>();
+        }
+
+        #[test]
+        fn packed_color_test() {
+            crate::testing::packed_color_test::<$reader>();
+        }
+
+        #[test]
+        fn read_u32_while_test() {
+            crate::testing::read_u32_while_test::<$reader>();
+        }
     };
 }
@@ -180,6 +180,7 @@ pub fn make_number_methods(stream: proc_macro::TokenStream) -> proc_macro::Token
     for num_info in NUMBERS.iter() {
         out.extend(num_info.apply_to_stream(in_stream.clone(), Endidness::Big));
         out.extend(num_info.apply_to_stream(in_stream.clone(), Endidness::Little));
+        out.extend(num_info.apply_to_stream(in_stream.clone(), Endidness::Native));
     }
     out.into()
 }